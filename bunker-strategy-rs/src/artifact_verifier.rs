@@ -1,31 +1,477 @@
-//! Supply Chain Trust — Sigstore artifact signature verification.
+//! Supply Chain Trust — Sigstore keyless artifact signature verification.
 //!
-//! Phase D stub. Verifies that a local artifact has a valid Sigstore/Rekor
-//! signature. No signing in Phase D (requires OIDC token — Phase I).
+//! Verifies that a local artifact carries a valid, transparency-logged
+//! Sigstore signature bound to an allow-listed OIDC identity:
+//!   1. The signing certificate chains to the cached Fulcio root and its
+//!      SAN/OIDC identity is a member of `VerificationPolicy::allowed_identities`.
+//!   2. The artifact signature verifies under the certificate's public key.
+//!   3. The bundle's Rekor transparency-log entry has a valid Merkle inclusion
+//!      proof against its signed tree head, and the signed-entry-timestamp is
+//!      signed by the cached Rekor key.
 //!
-//! UNVERIFIED: sigstore 0.13.0 verification API path.
+//! Scope honestly: [`fetch_trust_root`] fetches the two trust-root targets
+//! directly over TLS from the configured CDN rather than running a full TUF
+//! client (no root.json/snapshot/timestamp metadata chain is verified), and
+//! [`verify_rekor_timestamp`] verifies our own `RekorInclusionProof` summary
+//! of the log entry rather than Rekor's exact canonical JSON/DSSE envelope —
+//! both are real, executing checks, just scoped down from the full Sigstore
+//! protocol. Everything else (cert-chain signature check, SAN/identity
+//! check, ECDSA artifact-signature check, RFC 6962 Merkle inclusion math) is
+//! the genuine algorithm, not a placeholder.
+//!
+//! UNVERIFIED: exact API shapes for `x509-parser` (~0.16), `p256`/`ecdsa`
+//! (~0.13), `sha2` (~0.10), and `reqwest` (~0.11) — none of these are
+//! vendored in this checkout to check call signatures against.
+
+use std::time::{Duration, SystemTime};
+
+use ecdsa::signature::Verifier;
+use p256::ecdsa::{Signature, VerifyingKey};
+use p256::pkcs8::DecodePublicKey;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use x509_parser::certificate::X509Certificate;
+use x509_parser::extensions::{GeneralName, ParsedExtension};
+use x509_parser::prelude::FromDer;
 
-use std::path::Path;
+use crate::config::{
+    REKOR_PUBLIC_KEY_TARGET, SIGSTORE_TUF_METADATA_BASE_URL, TUF_TRUST_ROOT_REFRESH_INTERVAL,
+    TUF_TRUST_ROOT_TARGETS_PATH, FULCIO_ROOT_CERT_TARGET,
+};
 
 /// Error type for artifact verification operations.
-pub type VerifierError = Box<dyn std::error::Error + Send + Sync>;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArtifactError {
+    /// The TUF trust root could not be fetched and no cached copy is usable.
+    TrustRootUnavailable,
+    /// `bundle` is missing a field required to perform verification.
+    MissingBundleField(&'static str),
+    /// The signing certificate does not chain to the cached Fulcio root.
+    CertificateChainInvalid,
+    /// The certificate's SAN/OIDC identity is not in `allowed_identities`.
+    IdentityNotAllowed { subject: String },
+    /// The artifact signature does not verify under the certificate's key.
+    InvalidSignature,
+    /// The Rekor Merkle inclusion proof does not verify against the signed
+    /// tree head.
+    RekorInclusionInvalid,
+    /// The Rekor signed-entry-timestamp does not verify under the cached
+    /// Rekor key.
+    RekorTimestampInvalid,
+}
+
+impl std::fmt::Display for ArtifactError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TrustRootUnavailable => write!(f, "sigstore trust root unavailable"),
+            Self::MissingBundleField(field) => write!(f, "bundle missing field: {field}"),
+            Self::CertificateChainInvalid => {
+                write!(f, "signing certificate does not chain to the Fulcio root")
+            }
+            Self::IdentityNotAllowed { subject } => {
+                write!(f, "identity not in allow-list: {subject}")
+            }
+            Self::InvalidSignature => write!(f, "artifact signature does not verify"),
+            Self::RekorInclusionInvalid => write!(f, "Rekor inclusion proof does not verify"),
+            Self::RekorTimestampInvalid => write!(f, "Rekor signed-entry-timestamp does not verify"),
+        }
+    }
+}
+
+impl std::error::Error for ArtifactError {}
+
+// ---------------------------------------------------------------------------
+// Trust root — fetched and cached
+// ---------------------------------------------------------------------------
+
+/// The Sigstore trust material needed to verify a bundle: the Fulcio CA
+/// certificate (DER) and the Rekor public key, both distributed as TUF
+/// targets rather than pinned in source so they can rotate.
+pub struct TrustRoot {
+    /// DER-encoded Fulcio intermediate/root CA certificate.
+    pub fulcio_root_der: Vec<u8>,
+    /// Rekor public key, DER-encoded SubjectPublicKeyInfo.
+    pub rekor_key_spki: Vec<u8>,
+    fetched_at: SystemTime,
+}
+
+impl TrustRoot {
+    fn is_fresh(&self, now: SystemTime, refresh_interval: Duration) -> bool {
+        now.duration_since(self.fetched_at)
+            .map(|age| age < refresh_interval)
+            .unwrap_or(false)
+    }
+}
+
+/// Fetch (or return a still-fresh cached copy of) the Sigstore trust root
+/// from `metadata_base_url`.
+///
+/// Fetches the Fulcio CA certificate and Rekor public key targets directly
+/// over TLS from `metadata_base_url`. This is *not* a full TUF client: it
+/// does not verify a root.json trust chain, snapshot, or timestamp role —
+/// see the module doc's "Scope honestly" note. Both targets are expected to
+/// be PEM-encoded; this converts each to DER before returning.
+pub async fn fetch_trust_root(
+    metadata_base_url: &str,
+    refresh_interval: Duration,
+    cached: Option<TrustRoot>,
+) -> Result<TrustRoot, ArtifactError> {
+    let now = SystemTime::now();
+    if let Some(root) = &cached {
+        if root.is_fresh(now, refresh_interval) {
+            return Ok(cached.unwrap());
+        }
+    }
+
+    let fulcio_pem = fetch_target(metadata_base_url, FULCIO_ROOT_CERT_TARGET).await?;
+    let rekor_pem = fetch_target(metadata_base_url, REKOR_PUBLIC_KEY_TARGET).await?;
+
+    Ok(TrustRoot {
+        fulcio_root_der: pem_to_der(&fulcio_pem)?,
+        rekor_key_spki: pem_to_der(&rekor_pem)?,
+        fetched_at: now,
+    })
+}
+
+/// Fetch a single TUF target's raw (PEM) body over HTTPS.
+async fn fetch_target(metadata_base_url: &str, target_name: &str) -> Result<String, ArtifactError> {
+    let url = format!(
+        "{metadata_base_url}{TUF_TRUST_ROOT_TARGETS_PATH}/{target_name}"
+    );
+    reqwest::get(&url)
+        .await
+        .map_err(|_| ArtifactError::TrustRootUnavailable)?
+        .error_for_status()
+        .map_err(|_| ArtifactError::TrustRootUnavailable)?
+        .text()
+        .await
+        .map_err(|_| ArtifactError::TrustRootUnavailable)
+}
+
+/// Decode a single PEM block's body to DER.
+fn pem_to_der(pem_str: &str) -> Result<Vec<u8>, ArtifactError> {
+    pem::parse(pem_str)
+        .map(|block| block.contents().to_vec())
+        .map_err(|_| ArtifactError::TrustRootUnavailable)
+}
+
+/// Convenience wrapper over [`fetch_trust_root`] using the production
+/// Sigstore CDN and the configured refresh interval.
+pub async fn fetch_default_trust_root(
+    cached: Option<TrustRoot>,
+) -> Result<TrustRoot, ArtifactError> {
+    fetch_trust_root(
+        SIGSTORE_TUF_METADATA_BASE_URL,
+        TUF_TRUST_ROOT_REFRESH_INTERVAL,
+        cached,
+    )
+    .await
+}
+
+// ---------------------------------------------------------------------------
+// Bundle — the artifact's signature, certificate, and Rekor log entry
+// ---------------------------------------------------------------------------
+
+/// A Merkle inclusion proof for a single Rekor log entry, plus the signed
+/// tree head it is checked against.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RekorInclusionProof {
+    pub log_index: u64,
+    pub root_hash: [u8; 32],
+    pub tree_size: u64,
+    /// RFC 6962 leaf hash (`SHA256(0x00 ‖ entry_bytes)`) of the log entry
+    /// this proof is for.
+    pub leaf_hash: [u8; 32],
+    /// Sibling hashes from leaf to root, in order.
+    pub hashes: Vec<[u8; 32]>,
+    /// Rekor's signature over the signed tree head (`root_hash`, `tree_size`).
+    pub signed_tree_head_sig: Vec<u8>,
+}
+
+/// A Sigstore verification bundle: the signing certificate, the artifact
+/// signature, and the Rekor transparency-log entry binding the two together.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SigstoreBundle {
+    /// DER-encoded leaf (Fulcio-issued) signing certificate.
+    pub signing_cert_der: Vec<u8>,
+    /// Signature over the artifact bytes, produced by `signing_cert_der`'s key.
+    pub signature: Vec<u8>,
+    pub rekor_entry: RekorInclusionProof,
+    /// Rekor's signed-entry-timestamp over the log entry.
+    pub signed_entry_timestamp: Vec<u8>,
+}
+
+/// Caller-supplied policy: the set of OIDC identities (certificate SANs)
+/// permitted to sign artifacts this verifier will accept.
+pub struct VerificationPolicy {
+    pub allowed_identities: Vec<String>,
+}
+
+// ---------------------------------------------------------------------------
+// Verification
+// ---------------------------------------------------------------------------
+
+/// Verify that `bytes` is the artifact signed by `bundle`, transitively
+/// trusted via `trust_root`, and bound to an identity in `policy`.
+///
+/// Performs, in order:
+///   1. Certificate chain validation against `trust_root.fulcio_root_der`
+///      and an identity check against `policy.allowed_identities`.
+///   2. Signature verification of `bytes` under the certificate's public key.
+///   3. Rekor inclusion-proof and signed-entry-timestamp verification.
+///
+/// Returns `Ok(())` only if all three checks pass; any failure short-circuits
+/// with the first [`ArtifactError`] encountered.
+pub fn verify_artifact(
+    bytes: &[u8],
+    bundle: &SigstoreBundle,
+    trust_root: &TrustRoot,
+    policy: &VerificationPolicy,
+) -> Result<(), ArtifactError> {
+    verify_certificate_identity(&bundle.signing_cert_der, trust_root, policy)?;
+    verify_signature(bytes, &bundle.signing_cert_der, &bundle.signature)?;
+    verify_rekor_inclusion(&bundle.rekor_entry, trust_root)?;
+    verify_rekor_timestamp(&bundle.rekor_entry, &bundle.signed_entry_timestamp, trust_root)?;
+    Ok(())
+}
 
-/// Verify that a local artifact has a valid Sigstore/Rekor signature.
+/// Parse `signing_cert_der`'s public key into a P-256 verifying key.
 ///
-/// Returns:
-///   Ok(true)  — artifact has a valid, trusted signature
-///   Ok(false) — no signature bundle found (treat as unsigned, not as error)
-///   Err(...)  — verification infrastructure unreachable or signature invalid
+/// Fulcio-issued leaf certificates and the Rekor transparency-log key are
+/// both ECDSA P-256; this is shared by steps (1), (2), and (3b).
+fn parse_p256_spki(spki_der: &[u8]) -> Result<VerifyingKey, ()> {
+    VerifyingKey::from_public_key_der(spki_der).map_err(|_| ())
+}
+
+/// Step (1): the signing certificate chains to `trust_root.fulcio_root_der`
+/// and its embedded SAN/OIDC identity is in `policy.allowed_identities`.
+fn verify_certificate_identity(
+    signing_cert_der: &[u8],
+    trust_root: &TrustRoot,
+    policy: &VerificationPolicy,
+) -> Result<(), ArtifactError> {
+    let (_, leaf) = X509Certificate::from_der(signing_cert_der)
+        .map_err(|_| ArtifactError::CertificateChainInvalid)?;
+    let (_, root) = X509Certificate::from_der(&trust_root.fulcio_root_der)
+        .map_err(|_| ArtifactError::CertificateChainInvalid)?;
+
+    // Single-level chain: the Fulcio-issued leaf is checked directly against
+    // the cached root/intermediate, matching Sigstore's short-lived-leaf,
+    // no-further-intermediates issuance model.
+    leaf.verify_signature(Some(root.public_key()))
+        .map_err(|_| ArtifactError::CertificateChainInvalid)?;
+
+    let san_extension = leaf
+        .subject_alternative_name()
+        .map_err(|_| ArtifactError::CertificateChainInvalid)?
+        .ok_or(ArtifactError::CertificateChainInvalid)?;
+
+    let ParsedExtension::SubjectAlternativeName(san) = san_extension.parsed_extension() else {
+        return Err(ArtifactError::CertificateChainInvalid);
+    };
+
+    let identities: Vec<String> = san
+        .general_names
+        .iter()
+        .filter_map(|name| match name {
+            GeneralName::RFC822Name(s) => Some(s.to_string()),
+            GeneralName::URI(s) => Some(s.to_string()),
+            _ => None,
+        })
+        .collect();
+
+    if identities
+        .iter()
+        .any(|id| policy.allowed_identities.iter().any(|allowed| allowed == id))
+    {
+        Ok(())
+    } else {
+        Err(ArtifactError::IdentityNotAllowed {
+            subject: identities.join(","),
+        })
+    }
+}
+
+/// Step (2): `signature` verifies `bytes` under `signing_cert_der`'s public key.
+fn verify_signature(
+    bytes: &[u8],
+    signing_cert_der: &[u8],
+    signature: &[u8],
+) -> Result<(), ArtifactError> {
+    let (_, cert) =
+        X509Certificate::from_der(signing_cert_der).map_err(|_| ArtifactError::InvalidSignature)?;
+    let verifying_key =
+        parse_p256_spki(cert.public_key().raw).map_err(|()| ArtifactError::InvalidSignature)?;
+    let sig = Signature::from_der(signature).map_err(|_| ArtifactError::InvalidSignature)?;
+    verifying_key
+        .verify(bytes, &sig)
+        .map_err(|_| ArtifactError::InvalidSignature)
+}
+
+/// RFC 6962 Merkle node hash: `SHA256(0x01 ‖ left ‖ right)`.
+fn hash_children(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([0x01]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Recompute the Merkle tree root implied by an inclusion proof, per the
+/// standard RFC 6962 audit-path verification algorithm (as implemented by
+/// e.g. `certificate-transparency-go`'s `RootFromInclusionProof`).
 ///
-/// In Phase I this will:
-///   1. Locate the detached .sig / .bundle file alongside artifact_path
-///   2. Call the Sigstore verification API against the Rekor transparency log
-///   3. Return true only if the certificate chain is valid and the log entry exists
+/// Returns `None` if the proof is the wrong length for `(leaf_index, tree_size)`.
+fn root_from_inclusion_proof(
+    leaf_index: u64,
+    tree_size: u64,
+    leaf_hash: [u8; 32],
+    audit_path: &[[u8; 32]],
+) -> Option<[u8; 32]> {
+    if tree_size == 0 || leaf_index >= tree_size {
+        return None;
+    }
+    let mut node = leaf_index;
+    let mut last_node = tree_size - 1;
+    let mut hash = leaf_hash;
+
+    for sibling in audit_path {
+        if last_node == 0 {
+            return None; // proof longer than the tree supports
+        }
+        if node % 2 == 1 || node == last_node {
+            hash = hash_children(sibling, &hash);
+            while node % 2 == 0 && node != 0 {
+                node /= 2;
+                last_node /= 2;
+            }
+        } else {
+            hash = hash_children(&hash, sibling);
+        }
+        node /= 2;
+        last_node /= 2;
+    }
+
+    if last_node != 0 {
+        return None; // proof shorter than the tree requires
+    }
+    Some(hash)
+}
+
+/// Step (3a): `entry`'s Merkle inclusion proof verifies against its own
+/// `root_hash` / `tree_size` signed tree head.
+fn verify_rekor_inclusion(
+    entry: &RekorInclusionProof,
+    _trust_root: &TrustRoot,
+) -> Result<(), ArtifactError> {
+    let computed = root_from_inclusion_proof(
+        entry.log_index,
+        entry.tree_size,
+        entry.leaf_hash,
+        &entry.hashes,
+    )
+    .ok_or(ArtifactError::RekorInclusionInvalid)?;
+
+    if computed == entry.root_hash {
+        Ok(())
+    } else {
+        Err(ArtifactError::RekorInclusionInvalid)
+    }
+}
+
+/// Step (3b): `signed_entry_timestamp` verifies under `trust_root.rekor_key_spki`.
 ///
-/// UNVERIFIED: sigstore::cosign or sigstore::rekor entry point in 0.13.0.
-pub async fn verify_artifact_signature(
-    _artifact_path: &Path,
-) -> Result<bool, VerifierError> {
-    // Phase D stub
-    Err("Phase D stub — not implemented".into())
+/// Verifies an ECDSA/SHA-256 signature over `root_hash ‖ tree_size ‖
+/// log_index` (big-endian) — our own canonical summary of the signed tree
+/// head, not Rekor's exact wire-format entry body (see the module doc).
+fn verify_rekor_timestamp(
+    entry: &RekorInclusionProof,
+    signed_entry_timestamp: &[u8],
+    trust_root: &TrustRoot,
+) -> Result<(), ArtifactError> {
+    let verifying_key = parse_p256_spki(&trust_root.rekor_key_spki)
+        .map_err(|()| ArtifactError::RekorTimestampInvalid)?;
+    let sig = Signature::from_der(signed_entry_timestamp)
+        .map_err(|_| ArtifactError::RekorTimestampInvalid)?;
+
+    let mut signed_payload = Vec::with_capacity(32 + 8 + 8);
+    signed_payload.extend_from_slice(&entry.root_hash);
+    signed_payload.extend_from_slice(&entry.tree_size.to_be_bytes());
+    signed_payload.extend_from_slice(&entry.log_index.to_be_bytes());
+
+    verifying_key
+        .verify(&signed_payload, &sig)
+        .map_err(|_| ArtifactError::RekorTimestampInvalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A single-leaf tree: the root is just the leaf hash, no audit path.
+    #[test]
+    fn inclusion_proof_single_leaf_tree() {
+        let leaf_hash = [7u8; 32];
+        let root = root_from_inclusion_proof(0, 1, leaf_hash, &[]);
+        assert_eq!(root, Some(leaf_hash));
+    }
+
+    /// Two-leaf tree: root = hash_children(leaf0, leaf1), both leaves' audit
+    /// paths are the other leaf's hash.
+    #[test]
+    fn inclusion_proof_two_leaf_tree() {
+        let leaf0 = [1u8; 32];
+        let leaf1 = [2u8; 32];
+        let expected_root = hash_children(&leaf0, &leaf1);
+
+        assert_eq!(
+            root_from_inclusion_proof(0, 2, leaf0, &[leaf1]),
+            Some(expected_root)
+        );
+        assert_eq!(
+            root_from_inclusion_proof(1, 2, leaf1, &[leaf0]),
+            Some(expected_root)
+        );
+    }
+
+    #[test]
+    fn inclusion_proof_rejects_out_of_range_index() {
+        assert_eq!(root_from_inclusion_proof(5, 2, [0u8; 32], &[]), None);
+    }
+
+    #[test]
+    fn inclusion_proof_rejects_wrong_length_audit_path() {
+        // A single-leaf tree has no audit path to consume.
+        assert_eq!(
+            root_from_inclusion_proof(0, 1, [0u8; 32], &[[1u8; 32]]),
+            None
+        );
+    }
+
+    #[test]
+    fn verify_rekor_inclusion_detects_tampered_root() {
+        let leaf0 = [1u8; 32];
+        let leaf1 = [2u8; 32];
+        let good_root = hash_children(&leaf0, &leaf1);
+        let mut bad_root = good_root;
+        bad_root[0] ^= 0xFF;
+
+        let entry = RekorInclusionProof {
+            log_index: 0,
+            root_hash: bad_root,
+            tree_size: 2,
+            leaf_hash: leaf0,
+            hashes: vec![leaf1],
+            signed_tree_head_sig: Vec::new(),
+        };
+        let trust_root = TrustRoot {
+            fulcio_root_der: Vec::new(),
+            rekor_key_spki: Vec::new(),
+            fetched_at: SystemTime::now(),
+        };
+        assert!(matches!(
+            verify_rekor_inclusion(&entry, &trust_root),
+            Err(ArtifactError::RekorInclusionInvalid)
+        ));
+    }
 }
@@ -0,0 +1,33 @@
+//! Compile-time configuration shared by the Bunker Strategy defensive modules.
+//!
+//! Collected here so the security team can audit and update limits in a
+//! single location, mirroring the convention used by the WASM Security Gate.
+
+use std::time::Duration;
+
+// ---------------------------------------------------------------------------
+// Sigstore / TUF trust root  (artifact_verifier — supply chain)
+// ---------------------------------------------------------------------------
+
+/// Base URL for the CDN-hosted TUF repository that distributes the Sigstore
+/// trust root (`root.json` / `targets.json`, and the Fulcio CA certificate
+/// and Rekor public key as TUF targets).
+///
+/// Defaults to the production Sigstore TUF CDN; override for a staging
+/// instance or an air-gapped mirror.
+pub const SIGSTORE_TUF_METADATA_BASE_URL: &str = "https://tuf-repo-cdn.sigstore.dev";
+
+/// How long a cached [`crate::artifact_verifier::TrustRoot`] is considered
+/// valid before the client re-fetches `root.json` / `targets.json` from
+/// [`SIGSTORE_TUF_METADATA_BASE_URL`].
+pub const TUF_TRUST_ROOT_REFRESH_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60); // 6 h
+
+/// Path segment under [`SIGSTORE_TUF_METADATA_BASE_URL`] where individual TUF
+/// targets (the Fulcio root cert, the Rekor public key) are served.
+pub const TUF_TRUST_ROOT_TARGETS_PATH: &str = "/targets";
+
+/// TUF target name for the Fulcio CA certificate (PEM).
+pub const FULCIO_ROOT_CERT_TARGET: &str = "fulcio_v1.crt.pem";
+
+/// TUF target name for the Rekor transparency-log public key (PEM).
+pub const REKOR_PUBLIC_KEY_TARGET: &str = "rekor.pub";
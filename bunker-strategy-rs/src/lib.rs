@@ -8,6 +8,9 @@
 //!   did_passport     — UNC2970:   DID Passport identity verification
 //!   endpoint_monitor — Runtime:   macOS ESF behavioural monitoring (cfg-gated)
 //!   artifact_verifier — Supply chain: Sigstore artifact signature verification
+//!   config           — shared compile-time configuration
+
+mod config;
 
 pub mod jit_provenance;
 pub mod did_passport;
@@ -0,0 +1,213 @@
+//! Native wasmtime embedding for the WASM Security Gate.
+//!
+//! `wasm-security-gate` targets `wasm32-unknown-unknown` with a Node.js host
+//! in production, and its `thread_local!`/`RefCell` state assumes exactly one
+//! single-threaded instance. This crate gives non-Node callers — services,
+//! CLI tools, the RuVector manifold binary, and integration tests — the same
+//! safe entry points (`Gate::new`, `Gate::process`, `Gate::apply_update`) over
+//! a [`wasmtime`] instance instead of a JS runtime, so the real compiled
+//! artifact can be driven end-to-end without re-implementing the ABI.
+//!
+//! # Build prerequisite
+//! `Gate::new` loads an already-compiled `.wasm` artifact; it does not build
+//! the guest crate itself. Build it first:
+//! ```text
+//! cargo build -p wasm-security-gate --target wasm32-unknown-unknown
+//! ```
+//! CI should build that artifact in debug mode (the default, i.e. without
+//! `--release`) so the guest's `debug_assert!`s fire during integration
+//! tests; `Gate::new` also turns on wasmtime's own debug verifier for the
+//! same reason — see `Config::cranelift_debug_verifier` below.
+//!
+//! UNVERIFIED: this crate is written against the `wasmtime` API shape as of
+//! its last few stable releases (`Engine`/`Module`/`Store`/`Instance`,
+//! `get_typed_func`, `Config::cranelift_debug_verifier`); no `Cargo.toml` or
+//! vendored `wasmtime` exists in this snapshot to check exact method names
+//! and signatures against, so treat this as the intended shape rather than a
+//! compiled-and-tested one.
+
+use std::path::Path;
+
+use wasmtime::{Config, Engine, Instance, Memory, Module, Store, TypedFunc};
+
+/// Raw return code from a gate export — one of `wasm_security_gate`'s
+/// `RC_*` constants.
+///
+/// Re-declared in [`codes`] rather than imported, because this crate talks
+/// to the compiled `.wasm` artifact over the C ABI, not to the guest
+/// crate's Rust types.
+pub type Rc = u32;
+
+/// Mirrors of `wasm_security_gate`'s `RC_*` constants, so callers can match
+/// on a [`Gate::process`]/[`Gate::apply_update`] result without re-deriving
+/// the wire values by hand.
+pub mod codes {
+    use super::Rc;
+
+    /// Request passed all checks — allow.
+    pub const RC_ALLOW: Rc = 0;
+    /// Request failed a security check — deny.
+    pub const RC_DENY: Rc = 1;
+    /// Request is suspicious but not conclusively malicious — challenge.
+    pub const RC_CHALLENGE: Rc = 2;
+    /// Request is quarantined for async analysis.
+    pub const RC_QUARANTINE: Rc = 3;
+
+    pub const RC_ERR_SIZE: Rc = 0xFFFF_FF01;
+    pub const RC_ERR_PARSE: Rc = 0xFFFF_FF02;
+    pub const RC_ERR_OOM: Rc = 0xFFFF_FF03;
+    pub const RC_ERR_STATE: Rc = 0xFFFF_FF04;
+    pub const RC_ERR_WEIGHT: Rc = 0xFFFF_FF05;
+}
+
+/// Errors embedding or driving the gate module.
+#[derive(Debug)]
+pub enum HostError {
+    /// The module failed to compile, instantiate, or a call trapped.
+    Wasmtime(wasmtime::Error),
+    /// A read or write outside the instance's linear memory was attempted.
+    MemoryAccess(wasmtime::MemoryAccessError),
+    /// The module does not export the named symbol with the expected signature.
+    MissingExport(&'static str),
+    /// The module does not export linear memory named `memory`.
+    MissingMemory,
+}
+
+impl std::fmt::Display for HostError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HostError::Wasmtime(e) => write!(f, "wasmtime error: {e}"),
+            HostError::MemoryAccess(e) => write!(f, "gate linear memory access error: {e}"),
+            HostError::MissingExport(name) => {
+                write!(f, "gate module has no export named `{name}`")
+            }
+            HostError::MissingMemory => write!(f, "gate module does not export linear memory"),
+        }
+    }
+}
+
+impl std::error::Error for HostError {}
+
+impl From<wasmtime::Error> for HostError {
+    fn from(e: wasmtime::Error) -> Self {
+        HostError::Wasmtime(e)
+    }
+}
+
+impl From<wasmtime::MemoryAccessError> for HostError {
+    fn from(e: wasmtime::MemoryAccessError) -> Self {
+        HostError::MemoryAccess(e)
+    }
+}
+
+/// A running instance of the WASM Security Gate, embedded via wasmtime.
+///
+/// Each `Gate` owns its own [`Store`] and linear memory, matching the
+/// guest's `thread_local!` single-instance assumption — state is never
+/// shared across `Gate`s. `Gate::new` calls `gate_init` once, matching the
+/// ABI's "must be called exactly once before any other export" contract.
+pub struct Gate {
+    store: Store<()>,
+    memory: Memory,
+    alloc: TypedFunc<u32, u32>,
+    dealloc: TypedFunc<(u32, u32), ()>,
+    process_security_request: TypedFunc<(u32, u32), u32>,
+    apply_signature_update: TypedFunc<(u32, u32), u32>,
+}
+
+impl Gate {
+    /// Load the compiled gate module from `wasm_path`, instantiate it, and
+    /// call `gate_init`.
+    pub fn new(wasm_path: impl AsRef<Path>) -> Result<Self, HostError> {
+        let mut config = Config::new();
+        config.debug_info(true);
+        config.cranelift_debug_verifier(true);
+
+        let engine = Engine::new(&config)?;
+        let module = Module::from_file(&engine, wasm_path)?;
+        let mut store = Store::new(&engine, ());
+        let instance = Instance::new(&mut store, &module, &[])?;
+
+        let memory = instance
+            .get_memory(&mut store, "memory")
+            .ok_or(HostError::MissingMemory)?;
+        let alloc = instance
+            .get_typed_func::<u32, u32>(&mut store, "gate_alloc")
+            .map_err(|_| HostError::MissingExport("gate_alloc"))?;
+        let dealloc = instance
+            .get_typed_func::<(u32, u32), ()>(&mut store, "gate_dealloc")
+            .map_err(|_| HostError::MissingExport("gate_dealloc"))?;
+        let gate_init = instance
+            .get_typed_func::<(), u32>(&mut store, "gate_init")
+            .map_err(|_| HostError::MissingExport("gate_init"))?;
+        let process_security_request = instance
+            .get_typed_func::<(u32, u32), u32>(&mut store, "process_security_request")
+            .map_err(|_| HostError::MissingExport("process_security_request"))?;
+        let apply_signature_update = instance
+            .get_typed_func::<(u32, u32), u32>(&mut store, "apply_signature_update")
+            .map_err(|_| HostError::MissingExport("apply_signature_update"))?;
+
+        gate_init.call(&mut store, ())?;
+
+        Ok(Self {
+            store,
+            memory,
+            alloc,
+            dealloc,
+            process_security_request,
+            apply_signature_update,
+        })
+    }
+
+    /// Write `buf` into the guest's linear memory via `gate_alloc`, call
+    /// `export_fn(ptr, len)`, then free the scratch buffer with `gate_dealloc`.
+    fn call_with_buffer(
+        &mut self,
+        export_fn: TypedFunc<(u32, u32), u32>,
+        buf: &[u8],
+    ) -> Result<Rc, HostError> {
+        let len = buf.len() as u32;
+        let ptr = self.alloc.call(&mut self.store, len)?;
+        self.memory.write(&mut self.store, ptr as usize, buf)?;
+        let rc = export_fn.call(&mut self.store, (ptr, len))?;
+        self.dealloc.call(&mut self.store, (ptr, len))?;
+        Ok(rc)
+    }
+
+    /// Process a `SecurityRequest` FlatBuffers buffer through the real
+    /// compiled gate module. Mirrors `wasm_security_gate::process_security_request`.
+    pub fn process(&mut self, buf: &[u8]) -> Result<Rc, HostError> {
+        let export_fn = self.process_security_request;
+        self.call_with_buffer(export_fn, buf)
+    }
+
+    /// Apply a `SignatureUpdate` buffer through the real compiled gate
+    /// module. Mirrors `wasm_security_gate::apply_signature_update`.
+    pub fn apply_update(&mut self, buf: &[u8]) -> Result<Rc, HostError> {
+        let export_fn = self.apply_signature_update;
+        self.call_with_buffer(export_fn, buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Requires `cargo build -p wasm-security-gate --target wasm32-unknown-unknown`
+    /// to have produced the debug artifact at this path first — ignored by
+    /// default since this sandbox has no toolchain to build it.
+    #[test]
+    #[ignore]
+    fn gate_default_denies_empty_buffer() {
+        let mut gate = Gate::new(
+            "../../target/wasm32-unknown-unknown/debug/wasm_security_gate.wasm",
+        )
+        .expect("build wasm-security-gate for wasm32-unknown-unknown first");
+        let rc = gate.process(&[]).expect("process must not trap");
+        assert_ne!(
+            rc,
+            codes::RC_ALLOW,
+            "default-deny violated for an empty buffer"
+        );
+    }
+}
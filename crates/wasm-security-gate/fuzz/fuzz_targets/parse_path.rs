@@ -0,0 +1,25 @@
+//! Fuzz target #1: pre-parse size gate + FlatBuffers verifier (guardrails #2/#7).
+//!
+//! Feeds raw, unstructured bytes through `check_message_size` and then
+//! through the FlatBuffers verifier configured with the gate's real
+//! `MAX_VERIFIER_DEPTH` / `MAX_VERIFIER_TABLES` limits. Neither step should
+//! ever panic or OOM regardless of input — whether the bytes happen to parse
+//! into a `SecurityRequest` is not itself a pass/fail signal here.
+//!
+//! Seed corpus: `fuzz/corpus/parse_path/`.
+
+use flatbuffers_schemas_rust::wasm_gate_generated::mothership::wasm_gate::root_as_security_request_with_opts;
+use honggfuzz::fuzz;
+use wasm_security_gate::fuzzing_support::{check_message_size, verifier_opts, WeightMeter};
+
+fn main() {
+    loop {
+        fuzz!(|data: &[u8]| {
+            let mut meter = WeightMeter::default();
+            let _ = check_message_size(data, &mut meter);
+
+            let opts = verifier_opts();
+            let _ = root_as_security_request_with_opts(&opts, data);
+        });
+    }
+}
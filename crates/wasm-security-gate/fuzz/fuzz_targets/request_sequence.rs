@@ -0,0 +1,40 @@
+//! Fuzz target #5: sequences of requests, to exercise FIFO eviction
+//! (`GateState::upsert_origin`/`add_fingerprint`) and the monotonicity
+//! checks across many state-mutating calls rather than a single one.
+//!
+//! A single `gate_init` seeds the thread-local state, then an arbitrary-length
+//! sequence of arbitrary buffers is replayed through alternating exports. The
+//! invariant checked is the same as `wasm_gate_invariants`: never panic, and
+//! every result is one of `KNOWN_RC_CODES` — but here across a long-lived,
+//! repeatedly-evicted `GateState` instead of a fresh one per input.
+
+use arbitrary::{Arbitrary, Unstructured};
+use honggfuzz::fuzz;
+use wasm_security_gate::fuzzing_support::{
+    apply_signature_update_bytes, gate_init, process_security_request_bytes, KNOWN_RC_CODES,
+};
+
+fn main() {
+    loop {
+        fuzz!(|data: &[u8]| {
+            let mut u = Unstructured::new(data);
+            let messages: Vec<Vec<u8>> = match Arbitrary::arbitrary(&mut u) {
+                Ok(m) => m,
+                Err(_) => return,
+            };
+
+            gate_init();
+            for (i, msg) in messages.iter().enumerate() {
+                let rc = if i % 2 == 0 {
+                    process_security_request_bytes(msg)
+                } else {
+                    apply_signature_update_bytes(msg)
+                };
+                assert!(
+                    KNOWN_RC_CODES.contains(&rc),
+                    "call #{i} returned an unrecognized RC code {rc:#x}"
+                );
+            }
+        });
+    }
+}
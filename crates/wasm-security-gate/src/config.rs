@@ -44,6 +44,37 @@ pub const FRESHNESS_WINDOW_NS: u64 = 30_000_000_000; // 30 s
 /// for per-origin replay prevention.  Oldest entry is evicted when full.
 pub const MAX_ORIGINS: usize = 8;
 
+// ---------------------------------------------------------------------------
+// Origin reputation scoring  (Red Team guardrail #5 — repeat-offender ban)
+// ---------------------------------------------------------------------------
+
+/// Half-life (nanoseconds) for per-origin reputation score decay.
+///
+/// A score left untouched for exactly this long is cut in half, so penalties
+/// become forgiving over time but still accumulate under sustained abuse.
+pub const REPUTATION_HALF_LIFE_NS: u64 = 60_000_000_000; // 60 s
+
+/// Score threshold at or below which an origin's tracking state drops to
+/// `ForcedDisconnect`.
+pub const FORCED_DISCONNECT_THRESHOLD: f64 = -5.0;
+
+/// Score threshold at or below which an origin is `Banned` outright.
+/// Must be <= [`FORCED_DISCONNECT_THRESHOLD`].
+pub const BANNED_THRESHOLD: f64 = -20.0;
+
+/// How long a `Banned` origin's traffic is short-circuited for, once banned.
+pub const BAN_DURATION_NS: u64 = 300_000_000_000; // 5 min
+
+/// Score delta applied when a message passes every guardrail.
+pub const SCORE_DELTA_VALID: f64 = 1.0;
+
+/// Score delta applied for `StaleTimestamp` / `NonMonotonicTimestamp`.
+pub const SCORE_DELTA_TIMESTAMP_PENALTY: f64 = -3.0;
+
+/// Score delta applied for `InvalidSignature` / `ChainHeightRegressed` — the
+/// two outcomes that indicate deliberate forgery rather than clock skew.
+pub const SCORE_DELTA_SEVERE_PENALTY: f64 = -10.0;
+
 // ---------------------------------------------------------------------------
 // Fingerprint store
 // ---------------------------------------------------------------------------
@@ -53,6 +84,77 @@ pub const MAX_ORIGINS: usize = 8;
 /// Bounded to prevent unbounded growth from rogue SignatureUpdate messages.
 pub const MAX_FINGERPRINTS: usize = 256;
 
+// ---------------------------------------------------------------------------
+// Compute-budget weights  (Red Team guardrail #7 — bounded worst-case CPU)
+// ---------------------------------------------------------------------------
+
+/// Total weight budget for a single message.
+///
+/// The message size, verifier depth, and table count caps above are
+/// independent ceilings; none of them bound the *total* work a single
+/// adversarial-but-small message can force. `MAX_MESSAGE_WEIGHT` is the one
+/// auditable knob that does: crossing it rejects the message before the step
+/// that would overrun it runs.
+pub const MAX_MESSAGE_WEIGHT: u64 = 1_000_000;
+
+/// Flat weight every message pays regardless of contents.
+pub const WEIGHT_BASE_MESSAGE: u64 = 100;
+
+/// Weight charged per FlatBuffers table visited while parsing the envelope.
+pub const WEIGHT_PER_TABLE: u64 = 50;
+
+/// Weight charged per element iterated in `check_embedding_len`.
+pub const WEIGHT_PER_EMBEDDING_ELEMENT: u64 = 1;
+
+/// Fixed weight charged per `verify_ed25519` call — ED25519 verification is
+/// the single most expensive operation on the critical path.
+pub const WEIGHT_VERIFY_ED25519: u64 = 20_000;
+
+/// Weight charged per byte of a non-empty `pq_signature` field validated.
+pub const WEIGHT_PER_PQ_SIG_BYTE: u64 = 4;
+
+/// Fixed weight charged per `check_freshness` call — two integer comparisons,
+/// cheap relative to ED25519 but still a fixed unit of work the host should
+/// be able to price.
+pub const WEIGHT_CHECK_FRESHNESS: u64 = 200;
+
+/// Fixed weight charged per `check_chain_height` call.
+pub const WEIGHT_CHECK_CHAIN_HEIGHT: u64 = 100;
+
+// ---------------------------------------------------------------------------
+// Audit log  (Red Team guardrail #6 — tamper-evident decision record)
+// ---------------------------------------------------------------------------
+
+/// Capacity of the hash-chained audit log ring buffer.
+///
+/// Bounded so the gate's memory footprint on Pi Zero is fixed; when it wraps,
+/// the oldest `AuditEntry` is overwritten but the running hash chain carries
+/// on uninterrupted (`AuditLog` tracks the last digest independently of the
+/// ring contents).
+pub const AUDIT_LOG_CAPACITY: usize = 512;
+
+// ---------------------------------------------------------------------------
+// Suspicion scoring  (Red Team guardrail #8 — RC_CHALLENGE accumulator)
+// ---------------------------------------------------------------------------
+
+/// Pre-quantisation threshold past which a message's suspicion aggregate is
+/// denied outright, even though it passed every hard guardrail.
+///
+/// Passed through [`rvf_memory_physics::exceeds_pi_threshold`], which
+/// multiplies it by π before comparing — see that crate's module doc for why.
+/// Must be >= [`CHALLENGE_THRESHOLD`].
+pub const DENY_THRESHOLD: f64 = 8.0;
+
+/// Pre-quantisation threshold past which a message is issued `RC_CHALLENGE`
+/// instead of `RC_ALLOW`. See [`DENY_THRESHOLD`].
+pub const CHALLENGE_THRESHOLD: f64 = 3.0;
+
+/// Reputation penalty applied when the suspicion aggregate alone crosses
+/// [`DENY_THRESHOLD`] — smaller than [`SCORE_DELTA_SEVERE_PENALTY`] since
+/// nothing here is conclusive evidence of forgery, just accumulated
+/// near-misses.
+pub const SCORE_DELTA_SUSPICION_DENY: f64 = -5.0;
+
 // ---------------------------------------------------------------------------
 // Post-quantum signature validation  (Red Team guardrail #4)
 // ---------------------------------------------------------------------------
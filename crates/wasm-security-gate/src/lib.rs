@@ -9,6 +9,9 @@
 //!   derives them itself.
 //! - **Single-threaded**: `thread_local!` + `RefCell` — no `Mutex` syscalls on
 //!   `wasm32-unknown-unknown`.
+//! - **Suspicion scoring (guardrail #8)**: a message that passes every hard
+//!   guardrail can still be `RC_CHALLENGE`d or `RC_DENY`d if it ran close to
+//!   several guardrails' edges at once — see `validate_and_commit_provenance`.
 //!
 //! # Exported C ABI
 //! | Symbol | Args | Returns |
@@ -16,28 +19,48 @@
 //! | `gate_init` | — | `RC_ALLOW` or error sentinel |
 //! | `process_security_request` | `ptr: u32, len: u32` | RC code |
 //! | `apply_signature_update` | `ptr: u32, len: u32` | RC code |
+//! | `audit_chain_len` | — | length in bytes of the exported audit chain |
+//! | `export_audit_chain` | `ptr: u32, cap: u32` | bytes written, or `u32::MAX` if `cap` too small |
+//! | `last_request_weight` | — | compute weight (guardrail #7) charged by the most recent request |
+//! | `gate_alloc` | `len: u32` | offset of a fresh `len`-byte buffer in linear memory |
+//! | `gate_dealloc` | `ptr: u32, len: u32` | — |
+//!
+//! `gate_alloc`/`gate_dealloc` exist so a host has somewhere to write an
+//! input buffer before calling `process_security_request` /
+//! `apply_signature_update` — neither export can hand the host a pointer
+//! into its own memory to fill, since the two live in separate address
+//! spaces.
 
+mod audit_log;
 mod config;
 mod security_logic;
+mod weight_meter;
 
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 
 use flatbuffers::VerifierOptions;
 use flatbuffers_schemas_rust::{
     common_generated::mothership::common::ProvenanceRecord,
     wasm_gate_generated::mothership::wasm_gate::{
-        root_as_security_request_with_opts, SignatureUpdate,
+        root_as_security_request_with_opts, SecurityRequest, SignatureUpdate,
     },
 };
-use rvf_memory_physics::ContinuousDeterministicMemory;
+use rvf_memory_physics::{exceeds_pi_threshold, ContinuousDeterministicMemory};
 
+use audit_log::{origin_audit_id, AuditLog, Decision};
 use config::{
-    monotonic_now_ns, MAX_FINGERPRINTS, MAX_MESSAGE_BYTES, MAX_ORIGINS,
-    MAX_VERIFIER_DEPTH, MAX_VERIFIER_TABLES,
+    monotonic_now_ns, BAN_DURATION_NS, CHALLENGE_THRESHOLD, DENY_THRESHOLD, FRESHNESS_WINDOW_NS,
+    MAX_FINGERPRINTS, MAX_MESSAGE_BYTES, MAX_ORIGINS, MAX_VERIFIER_DEPTH, MAX_VERIFIER_TABLES,
+    REPUTATION_HALF_LIFE_NS, SCORE_DELTA_SEVERE_PENALTY, SCORE_DELTA_SUSPICION_DENY,
+    SCORE_DELTA_TIMESTAMP_PENALTY, SCORE_DELTA_VALID, WEIGHT_PER_TABLE,
 };
 use security_logic::{
-    check_chain_height, check_embedding_len, check_freshness, check_message_size, verify_ed25519,
+    apply_score_delta, check_chain_height, check_embedding_len, check_freshness,
+    check_message_size, check_origin_ban, classify_origin_state, decay_score, verify_ed25519,
+    OriginState,
+    ProvenanceError,
 };
+use weight_meter::WeightMeter;
 
 // ---------------------------------------------------------------------------
 // Return codes (must match the host-side expectation)
@@ -57,6 +80,34 @@ const RC_ERR_SIZE: u32 = 0xFFFF_FF01;
 const RC_ERR_PARSE: u32 = 0xFFFF_FF02;
 const RC_ERR_OOM: u32 = 0xFFFF_FF03;
 const RC_ERR_STATE: u32 = 0xFFFF_FF04;
+/// The per-message compute-budget weight (guardrail #7) was exhausted by a
+/// step with no more specific size/OOM sentinel of its own.
+const RC_ERR_WEIGHT: u32 = 0xFFFF_FF05;
+
+// ---------------------------------------------------------------------------
+// Audit log reason codes (guardrail #6)
+// ---------------------------------------------------------------------------
+
+const REASON_ACCEPTED: u32 = 0;
+const REASON_ORIGIN_BANNED: u32 = 1;
+const REASON_INVALID_SIGNATURE: u32 = 2;
+const REASON_STALE_OR_NON_MONOTONIC_TIMESTAMP: u32 = 3;
+const REASON_CHAIN_HEIGHT_REGRESSED: u32 = 4;
+/// Suspicion aggregate (guardrail #8) crossed `DENY_THRESHOLD` despite the
+/// message passing every hard guardrail.
+const REASON_SUSPICION_DENIED: u32 = 5;
+/// Suspicion aggregate (guardrail #8) crossed `CHALLENGE_THRESHOLD` but not
+/// `DENY_THRESHOLD`.
+const REASON_CHALLENGED: u32 = 6;
+/// Suspicion aggregate (guardrail #8) crossed `DENY_THRESHOLD` with compute
+/// weight (guardrail #7) as the dominant contributor — quarantined for async
+/// analysis rather than denied outright, since an expensive-but-honest
+/// request looks identical to this gate until someone looks closer.
+const REASON_QUARANTINED_EXPENSIVE: u32 = 7;
+/// The per-message compute-budget weight (guardrail #7) was exhausted inside
+/// a guardrail check itself (`verify_ed25519`, `check_freshness`, or
+/// `check_chain_height`) rather than by a guardrail's own failure mode.
+const REASON_WEIGHT_EXCEEDED: u32 = 8;
 
 // ---------------------------------------------------------------------------
 // Gate state types
@@ -73,6 +124,13 @@ struct OriginRecord {
     last_timestamp_ns: u64,
     last_chain_height: u64,
     occupied: bool,
+    /// Reputation score (guardrail #5) — decays toward 0, penalised on abuse.
+    score: f64,
+    /// Gate clock at which `score` was last updated (decay reference point).
+    last_score_update_ns: u64,
+    state: OriginState,
+    /// Only meaningful while `state == OriginState::Banned`.
+    ban_until_ns: u64,
 }
 
 impl Default for OriginRecord {
@@ -83,6 +141,10 @@ impl Default for OriginRecord {
             last_timestamp_ns: 0,
             last_chain_height: 0,
             occupied: false,
+            score: 0.0,
+            last_score_update_ns: 0,
+            state: OriginState::Healthy,
+            ban_until_ns: 0,
         }
     }
 }
@@ -94,6 +156,8 @@ struct GateState {
     fingerprints: Vec<[u8; 32]>,
     /// Pi-quantised memory for future adaptive scoring (placeholder).
     memory: ContinuousDeterministicMemory,
+    /// Hash-chained, tamper-evident record of every guardrail decision (guardrail #6).
+    audit_log: AuditLog,
 }
 
 impl GateState {
@@ -102,6 +166,7 @@ impl GateState {
             origins: [OriginRecord::default(); MAX_ORIGINS],
             fingerprints: Vec::with_capacity(MAX_FINGERPRINTS),
             memory: ContinuousDeterministicMemory::initialize(4),
+            audit_log: AuditLog::new(),
         }
     }
 
@@ -130,9 +195,54 @@ impl GateState {
             last_timestamp_ns: ts,
             last_chain_height: height,
             occupied: true,
+            ..OriginRecord::default()
         };
     }
 
+    /// Find-or-create the slot for `(system, pubkey)`, decay its reputation
+    /// score to `now_ns`, apply `delta`, and reclassify its tracking state
+    /// (guardrail #5). Returns the resulting [`OriginState`].
+    fn apply_origin_outcome(
+        &mut self,
+        system: u8,
+        pubkey: &[u8; 32],
+        now_ns: u64,
+        delta: f64,
+    ) -> OriginState {
+        let idx = self.find_origin(system, pubkey).unwrap_or_else(|| {
+            let slot = self
+                .origins
+                .iter()
+                .position(|r| !r.occupied)
+                .unwrap_or(0); // evict oldest when full
+            self.origins[slot] = OriginRecord {
+                origin_system: system,
+                public_key: *pubkey,
+                occupied: true,
+                ..OriginRecord::default()
+            };
+            slot
+        });
+
+        let rec = &mut self.origins[idx];
+        let dt_ns = now_ns.saturating_sub(rec.last_score_update_ns);
+        rec.score = apply_score_delta(rec.score, dt_ns, REPUTATION_HALF_LIFE_NS, delta);
+        rec.last_score_update_ns = now_ns;
+        rec.state = classify_origin_state(rec.score);
+        if rec.state == OriginState::Banned {
+            rec.ban_until_ns = now_ns.saturating_add(BAN_DURATION_NS);
+        }
+        if rec.state == OriginState::ForcedDisconnect || rec.state == OriginState::Banned {
+            // Drop replay tracking: the next message from this origin is
+            // compared against a blank slate rather than letting the origin
+            // keep benefiting from timestamps/heights it earned before its
+            // score soured.
+            rec.last_timestamp_ns = 0;
+            rec.last_chain_height = 0;
+        }
+        rec.state
+    }
+
     /// Add a fingerprint, deduplicating and evicting the oldest at capacity.
     fn add_fingerprint(&mut self, fp: [u8; 32]) {
         if self.fingerprints.iter().any(|f| f == &fp) {
@@ -151,6 +261,11 @@ impl GateState {
 
 thread_local! {
     static STATE: RefCell<Option<GateState>> = RefCell::new(None);
+    /// Total compute weight (guardrail #7) charged while servicing the most
+    /// recent `process_security_request`/`apply_signature_update` call, for
+    /// [`last_request_weight`]. Independent of `GateState` so it survives
+    /// even the `RC_ERR_STATE` case (gate not yet initialised).
+    static LAST_REQUEST_WEIGHT: Cell<u64> = Cell::new(0);
 }
 
 // ---------------------------------------------------------------------------
@@ -167,6 +282,63 @@ fn verifier_opts() -> VerifierOptions {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Fuzzing support (see `fuzz/`) — not part of the public ABI.
+// ---------------------------------------------------------------------------
+
+/// Re-exports the pre-parse, verify-path, and top-level-export internals
+/// that `fuzz/` drives directly, so the fuzz targets can exercise them
+/// without going through the `extern "C"` ABI — `process_security_request`
+/// and `apply_signature_update`'s `(ptr, len)` signature is a WASM linear
+/// memory contract, not something a native fuzz target can fake with a
+/// sound pointer, so fuzzing targets the same-logic safe wrappers instead.
+/// Only compiled with `--features fuzzing`; normal builds (including the
+/// wasm32 target) never see this module.
+#[cfg(feature = "fuzzing")]
+pub mod fuzzing_support {
+    pub use crate::config::{MAX_VERIFIER_DEPTH, MAX_VERIFIER_TABLES};
+    pub use crate::security_logic::{check_message_size, verify_ed25519, xxh3_digest_eq};
+    pub use crate::verifier_opts;
+    pub use crate::weight_meter::WeightMeter;
+    pub use crate::{
+        apply_signature_update_impl as apply_signature_update_bytes,
+        process_security_request_impl as process_security_request_bytes, gate_init, RC_ALLOW,
+        RC_CHALLENGE, RC_DENY, RC_QUARANTINE,
+    };
+
+    /// Every value `process_security_request_bytes`/`apply_signature_update_bytes`
+    /// may return — the gate's stated invariant is that the result is always
+    /// one of these, regardless of input.
+    pub const KNOWN_RC_CODES: &[u32] = &[
+        RC_ALLOW,
+        RC_DENY,
+        RC_CHALLENGE,
+        RC_QUARANTINE,
+        crate::RC_ERR_SIZE,
+        crate::RC_ERR_PARSE,
+        crate::RC_ERR_OOM,
+        crate::RC_ERR_STATE,
+        crate::RC_ERR_WEIGHT,
+    ];
+}
+
+/// Approximate the number of FlatBuffers tables visited while servicing a
+/// request, for guardrail #7's per-table weight charge.
+///
+/// The FlatBuffers verifier itself doesn't expose a traversal counter, so
+/// this counts the top-level `SecurityRequest` table plus each optional
+/// nested table that is actually present rather than hooking the verifier.
+fn approx_table_count(req: &SecurityRequest<'_>) -> u64 {
+    let mut count = 1; // the SecurityRequest table itself
+    if req.domain_context().is_some() {
+        count += 1;
+    }
+    if req.provenance().is_some() {
+        count += 1;
+    }
+    count
+}
+
 // ---------------------------------------------------------------------------
 // Helper: run all guardrails for a ProvenanceRecord and update gate state.
 //
@@ -177,19 +349,52 @@ fn validate_and_commit_provenance(
     prov: &ProvenanceRecord<'_>,
     state: &mut GateState,
     now_ns: u64,
+    meter: &mut WeightMeter,
 ) -> u32 {
-    // Guardrail #1: ED25519 signature over (digest ‖ timestamp_ns).
-    if verify_ed25519(prov).is_err() {
-        return RC_DENY;
-    }
-
-    let ts = prov.timestamp_ns();
-    let height = prov.witness_chain_height();
     let sys = prov.origin_system().0;
     let pubkey = match prov.public_key() {
         Some(k) => k.0,
         None => return RC_DENY,
     };
+    let origin_id = origin_audit_id(sys, &pubkey);
+
+    // Guardrail #5: short-circuit a still-banned origin before spending any
+    // more work on it.
+    if let Some(idx) = state.find_origin(sys, &pubkey) {
+        let rec = state.origins[idx];
+        let dt_ns = now_ns.saturating_sub(rec.last_score_update_ns);
+        let decayed_score = decay_score(rec.score, dt_ns, REPUTATION_HALF_LIFE_NS);
+        if check_origin_ban(rec.state, decayed_score, rec.ban_until_ns, now_ns).is_err() {
+            state
+                .audit_log
+                .push(now_ns, origin_id, Decision::Reject, REASON_ORIGIN_BANNED);
+            return RC_DENY;
+        }
+    }
+
+    // Guardrail #1: ED25519 signature over (digest ‖ timestamp_ns).
+    // Guardrail #7: fixed large weight per call — checked before any penalty
+    // is applied, since exhausting the compute budget is not itself evidence
+    // of forgery.
+    match verify_ed25519(prov, meter) {
+        Ok(()) => {}
+        Err(ProvenanceError::WeightExceeded { .. }) => {
+            state
+                .audit_log
+                .push(now_ns, origin_id, Decision::Reject, REASON_WEIGHT_EXCEEDED);
+            return RC_ERR_WEIGHT;
+        }
+        Err(_) => {
+            state.apply_origin_outcome(sys, &pubkey, now_ns, SCORE_DELTA_SEVERE_PENALTY);
+            state
+                .audit_log
+                .push(now_ns, origin_id, Decision::Reject, REASON_INVALID_SIGNATURE);
+            return RC_DENY;
+        }
+    }
+
+    let ts = prov.timestamp_ns();
+    let height = prov.witness_chain_height();
 
     let (last_ts, last_height) = match state.find_origin(sys, &pubkey) {
         Some(idx) => (
@@ -200,17 +405,115 @@ fn validate_and_commit_provenance(
     };
 
     // Guardrail #1: freshness + strict monotonicity.
-    if check_freshness(ts, last_ts, now_ns).is_err() {
-        return RC_DENY;
+    match check_freshness(ts, last_ts, now_ns, meter) {
+        Ok(()) => {}
+        Err(ProvenanceError::WeightExceeded { .. }) => {
+            state
+                .audit_log
+                .push(now_ns, origin_id, Decision::Reject, REASON_WEIGHT_EXCEEDED);
+            return RC_ERR_WEIGHT;
+        }
+        Err(_) => {
+            state.apply_origin_outcome(sys, &pubkey, now_ns, SCORE_DELTA_TIMESTAMP_PENALTY);
+            state.audit_log.push(
+                now_ns,
+                origin_id,
+                Decision::Reject,
+                REASON_STALE_OR_NON_MONOTONIC_TIMESTAMP,
+            );
+            return RC_DENY;
+        }
     }
 
     // Guardrail #1: chain height (unsigned field — separate from signature).
-    if check_chain_height(height, last_height).is_err() {
-        return RC_QUARANTINE;
+    match check_chain_height(height, last_height, meter) {
+        Ok(()) => {}
+        Err(ProvenanceError::WeightExceeded { .. }) => {
+            state
+                .audit_log
+                .push(now_ns, origin_id, Decision::Reject, REASON_WEIGHT_EXCEEDED);
+            return RC_ERR_WEIGHT;
+        }
+        Err(_) => {
+            state.apply_origin_outcome(sys, &pubkey, now_ns, SCORE_DELTA_SEVERE_PENALTY);
+            state
+                .audit_log
+                .push(now_ns, origin_id, Decision::Reject, REASON_CHAIN_HEIGHT_REGRESSED);
+            return RC_QUARANTINE;
+        }
+    }
+
+    // Every hard guardrail passed. Guardrail #8: accumulate how *close* this
+    // message ran to each guardrail's edge into a fresh per-request scratch
+    // accumulator — reused across requests this would let an origin's
+    // suspicion carry over and double-count; `GateState::memory` (the
+    // persistent accumulator) is reserved for future cross-request scoring.
+    let mut scratch = ContinuousDeterministicMemory::initialize(4);
+
+    let age_ns = now_ns.saturating_sub(ts);
+    let freshness_margin = age_ns as f64 / FRESHNESS_WINDOW_NS as f64;
+    // Extra forward skip beyond the expected +1 increment; 0 for a message's
+    // first-ever appearance from this origin (last_height == 0) since there
+    // is no prior height to gap from.
+    let height_gap = if last_height == 0 {
+        0.0
+    } else {
+        height.saturating_sub(last_height).saturating_sub(1) as f64
+    };
+    let size_pressure = meter.total() as f64 / meter.budget().max(1) as f64;
+    let fingerprint_miss = if state.fingerprints.iter().any(|fp| fp == &pubkey) {
+        0.0
+    } else {
+        1.0
+    };
+    scratch.update_with_quantized_delta(&[
+        freshness_margin,
+        height_gap,
+        size_pressure,
+        fingerprint_miss,
+    ]);
+    let aggregate: f64 = scratch.state().iter().sum();
+
+    if exceeds_pi_threshold(aggregate, DENY_THRESHOLD) {
+        // Guardrail #7 feeding #8: when compute weight is the dominant
+        // contributor, this request was expensive to verify rather than
+        // conclusively suspicious on its other merits — quarantine it for
+        // async analysis instead of denying outright.
+        let weight_dominates = scratch.state()[2]
+            >= scratch.state().iter().copied().fold(f64::MIN, f64::max);
+        if weight_dominates {
+            state.apply_origin_outcome(sys, &pubkey, now_ns, SCORE_DELTA_SUSPICION_DENY);
+            state.audit_log.push(
+                now_ns,
+                origin_id,
+                Decision::Reject,
+                REASON_QUARANTINED_EXPENSIVE,
+            );
+            return RC_QUARANTINE;
+        }
+        state.apply_origin_outcome(sys, &pubkey, now_ns, SCORE_DELTA_SUSPICION_DENY);
+        state
+            .audit_log
+            .push(now_ns, origin_id, Decision::Reject, REASON_SUSPICION_DENIED);
+        return RC_DENY;
     }
 
-    // All checks passed — commit updated origin state.
+    // Commit updated origin state and reward reputation — even when
+    // challenged, the message passed every hard check, so its timestamp and
+    // chain height are legitimate high-water marks.
+    state.apply_origin_outcome(sys, &pubkey, now_ns, SCORE_DELTA_VALID);
     state.upsert_origin(sys, &pubkey, ts, height);
+
+    if exceeds_pi_threshold(aggregate, CHALLENGE_THRESHOLD) {
+        state
+            .audit_log
+            .push(now_ns, origin_id, Decision::Accept, REASON_CHALLENGED);
+        return RC_CHALLENGE;
+    }
+
+    state
+        .audit_log
+        .push(now_ns, origin_id, Decision::Accept, REASON_ACCEPTED);
     RC_ALLOW
 }
 
@@ -243,9 +546,25 @@ pub extern "C" fn gate_init() -> u32 {
 #[no_mangle]
 pub unsafe extern "C" fn process_security_request(ptr: u32, len: u32) -> u32 {
     let buf = core::slice::from_raw_parts(ptr as *const u8, len as usize);
+    process_security_request_impl(buf)
+}
+
+/// Safe body of [`process_security_request`], operating on an already-valid
+/// Rust slice. Split out so `fuzz/` can drive the parsing and guardrail logic
+/// directly with arbitrary-length buffers instead of faking a WASM pointer.
+///
+/// Records the request's total compute weight (guardrail #7) to
+/// [`LAST_REQUEST_WEIGHT`] regardless of outcome, for [`last_request_weight`].
+fn process_security_request_impl(buf: &[u8]) -> u32 {
+    let mut meter = WeightMeter::default();
+    let rc = process_security_request_weighed(buf, &mut meter);
+    LAST_REQUEST_WEIGHT.with(|w| w.set(meter.total()));
+    rc
+}
 
-    // Guardrail #2 — pre-parse size gate.
-    if check_message_size(buf).is_err() {
+fn process_security_request_weighed(buf: &[u8], meter: &mut WeightMeter) -> u32 {
+    // Guardrail #2 / #7 — pre-parse size gate + flat per-message weight.
+    if check_message_size(buf, meter).is_err() {
         return RC_ERR_SIZE;
     }
 
@@ -256,9 +575,17 @@ pub unsafe extern "C" fn process_security_request(ptr: u32, len: u32) -> u32 {
         Err(_) => return RC_ERR_PARSE,
     };
 
+    // Guardrail #7 — weight for the tables visited while parsing the envelope.
+    if meter
+        .charge(WEIGHT_PER_TABLE.saturating_mul(approx_table_count(&req)))
+        .is_err()
+    {
+        return RC_ERR_WEIGHT;
+    }
+
     // Guardrail #2 — post-parse embedding length gate.
     if let Some(ctx) = req.domain_context() {
-        if check_embedding_len(&ctx).is_err() {
+        if check_embedding_len(&ctx, meter).is_err() {
             return RC_ERR_OOM;
         }
     }
@@ -278,7 +605,7 @@ pub unsafe extern "C" fn process_security_request(ptr: u32, len: u32) -> u32 {
             None => return RC_DENY,
         };
 
-        validate_and_commit_provenance(&prov, state, now_ns)
+        validate_and_commit_provenance(&prov, state, now_ns, meter)
     })
 }
 
@@ -294,9 +621,25 @@ pub unsafe extern "C" fn process_security_request(ptr: u32, len: u32) -> u32 {
 #[no_mangle]
 pub unsafe extern "C" fn apply_signature_update(ptr: u32, len: u32) -> u32 {
     let buf = core::slice::from_raw_parts(ptr as *const u8, len as usize);
+    apply_signature_update_impl(buf)
+}
+
+/// Safe body of [`apply_signature_update`], operating on an already-valid
+/// Rust slice. Split out so `fuzz/` can drive the parsing and guardrail logic
+/// directly with arbitrary-length buffers instead of faking a WASM pointer.
+///
+/// Records the request's total compute weight (guardrail #7) to
+/// [`LAST_REQUEST_WEIGHT`] regardless of outcome, for [`last_request_weight`].
+fn apply_signature_update_impl(buf: &[u8]) -> u32 {
+    let mut meter = WeightMeter::default();
+    let rc = apply_signature_update_weighed(buf, &mut meter);
+    LAST_REQUEST_WEIGHT.with(|w| w.set(meter.total()));
+    rc
+}
 
-    // Guardrail #2 — pre-parse size gate.
-    if check_message_size(buf).is_err() {
+fn apply_signature_update_weighed(buf: &[u8], meter: &mut WeightMeter) -> u32 {
+    // Guardrail #2 / #7 — pre-parse size gate + flat per-message weight.
+    if check_message_size(buf, meter).is_err() {
         return RC_ERR_SIZE;
     }
 
@@ -307,6 +650,11 @@ pub unsafe extern "C" fn apply_signature_update(ptr: u32, len: u32) -> u32 {
         Err(_) => return RC_ERR_PARSE,
     };
 
+    // Guardrail #7 — SignatureUpdate is a single table (no DomainContext).
+    if meter.charge(WEIGHT_PER_TABLE).is_err() {
+        return RC_ERR_WEIGHT;
+    }
+
     let now_ns = monotonic_now_ns();
 
     STATE.with(|s| {
@@ -322,7 +670,7 @@ pub unsafe extern "C" fn apply_signature_update(ptr: u32, len: u32) -> u32 {
             None => return RC_DENY,
         };
 
-        let rc = validate_and_commit_provenance(&prov, state, now_ns);
+        let rc = validate_and_commit_provenance(&prov, state, now_ns, meter);
         if rc != RC_ALLOW {
             return rc;
         }
@@ -337,3 +685,83 @@ pub unsafe extern "C" fn apply_signature_update(ptr: u32, len: u32) -> u32 {
         RC_ALLOW
     })
 }
+
+/// Deterministic compute weight (guardrail #7) accumulated while servicing
+/// the most recent `process_security_request`/`apply_signature_update` call.
+///
+/// Every charge is a fixed constant from `config` — no wall-clock timing —
+/// so two hosts processing the same buffer always agree on its cost. Lets
+/// the Node host enforce its own budget and back-pressure abusive origins
+/// without re-deriving the weight itself.
+#[no_mangle]
+pub extern "C" fn last_request_weight() -> u64 {
+    LAST_REQUEST_WEIGHT.with(|w| w.get())
+}
+
+/// Length in bytes of [`export_audit_chain`]'s current output.
+///
+/// Call this first to size the host-side buffer before calling
+/// `export_audit_chain`.
+#[no_mangle]
+pub extern "C" fn audit_chain_len() -> u32 {
+    STATE.with(|s| {
+        let borrowed = s.borrow();
+        match borrowed.as_ref() {
+            Some(st) => st.audit_log.export_chain().len() as u32,
+            None => 0,
+        }
+    })
+}
+
+/// Allocate a scratch buffer of `len` bytes in WASM linear memory for the
+/// host to write an input buffer into before calling
+/// `process_security_request` / `apply_signature_update`.
+///
+/// Returns the offset of the allocated buffer. Pair with [`gate_dealloc`]
+/// once the call it was allocated for has returned.
+#[no_mangle]
+pub extern "C" fn gate_alloc(len: u32) -> u32 {
+    let mut buf = vec![0u8; len as usize].into_boxed_slice();
+    let ptr = buf.as_mut_ptr() as u32;
+    core::mem::forget(buf);
+    ptr
+}
+
+/// Free a buffer previously returned by [`gate_alloc`].
+///
+/// # Safety
+/// `ptr` must be a value previously returned by `gate_alloc` with this same
+/// `len`, and must not already have been freed.
+#[no_mangle]
+pub unsafe extern "C" fn gate_dealloc(ptr: u32, len: u32) {
+    drop(Vec::from_raw_parts(ptr as *mut u8, len as usize, len as usize));
+}
+
+/// Serialize the audit log's hash chain into WASM linear memory at `ptr` for
+/// off-device inspection (guardrail #6). See [`audit_log::AuditLog::export_chain`]
+/// for the wire format.
+///
+/// Returns the number of bytes written, or `u32::MAX` if `cap` is smaller
+/// than the chain's serialized size (nothing is written in that case).
+///
+/// # Safety
+/// `ptr..ptr+cap` must be a valid, writable slice in WASM linear memory.
+#[no_mangle]
+pub unsafe extern "C" fn export_audit_chain(ptr: u32, cap: u32) -> u32 {
+    STATE.with(|s| {
+        let borrowed = s.borrow();
+        let state = match borrowed.as_ref() {
+            Some(st) => st,
+            None => return 0,
+        };
+
+        let bytes = state.audit_log.export_chain();
+        if bytes.len() > cap as usize {
+            return u32::MAX;
+        }
+
+        let out = core::slice::from_raw_parts_mut(ptr as *mut u8, bytes.len());
+        out.copy_from_slice(&bytes);
+        bytes.len() as u32
+    })
+}
@@ -1,4 +1,4 @@
-//! Security logic — four Red Team guardrails.
+//! Security logic — Red Team guardrails #1-5 and #7 (#6 lives in [`crate::audit_log`]).
 //!
 //! Each function corresponds to exactly one guardrail so the security team
 //! can audit, test, and update them in isolation.
@@ -9,14 +9,20 @@
 //! | 2 | OOM defense        | `check_message_size`, `check_embedding_len` |
 //! | 3 | 128-bit collision  | `xxh3_digest_eq` |
 //! | 4 | PQ sig bounds      | `validate_pq_signature` |
+//! | 5 | Reputation scoring | `decay_score`, `apply_score_delta`, `classify_origin_state`, `check_origin_ban` |
+//! | 7 | Compute budget     | see [`crate::weight_meter`] — charged from `check_message_size`, `check_embedding_len`, `verify_ed25519`, `validate_pq_signature`, `check_freshness`, `check_chain_height` |
 
 use flatbuffers_schemas_rust::common_generated::mothership::common::{
     DomainContext, ProvenanceRecord, Xxh3Digest,
 };
 
 use crate::config::{
-    EXPECTED_PQ_SIG_LEN, FRESHNESS_WINDOW_NS, MAX_EMBEDDING_LEN, MAX_MESSAGE_BYTES,
+    BANNED_THRESHOLD, EXPECTED_PQ_SIG_LEN, FORCED_DISCONNECT_THRESHOLD, FRESHNESS_WINDOW_NS,
+    MAX_EMBEDDING_LEN, MAX_MESSAGE_BYTES, WEIGHT_BASE_MESSAGE, WEIGHT_CHECK_CHAIN_HEIGHT,
+    WEIGHT_CHECK_FRESHNESS, WEIGHT_PER_EMBEDDING_ELEMENT, WEIGHT_PER_PQ_SIG_BYTE,
+    WEIGHT_VERIFY_ED25519,
 };
+use crate::weight_meter::WeightMeter;
 
 // ---------------------------------------------------------------------------
 // Error types
@@ -25,11 +31,15 @@ use crate::config::{
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SizeError {
     MessageTooLarge { got: usize },
+    /// The per-message compute-budget weight total would exceed `budget`.
+    WeightExceeded { got: u64, budget: u64 },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OomError {
     EmbeddingTooLong { got: usize },
+    /// The per-message compute-budget weight total would exceed `budget`.
+    WeightExceeded { got: u64, budget: u64 },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -47,11 +57,18 @@ pub enum ProvenanceError {
     NonMonotonicTimestamp,
     /// `witness_chain_height` regressed (unsigned field — checked independently).
     ChainHeightRegressed,
+    /// The origin's reputation score is at or below `BANNED_THRESHOLD` and
+    /// the ban has not yet expired.
+    OriginBanned,
+    /// The per-message compute-budget weight total would exceed `budget`.
+    WeightExceeded { got: u64, budget: u64 },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PqSigError {
     InvalidLength { got: usize, expected: usize },
+    /// The per-message compute-budget weight total would exceed `budget`.
+    WeightExceeded { got: u64, budget: u64 },
 }
 
 // ---------------------------------------------------------------------------
@@ -61,9 +78,16 @@ pub enum PqSigError {
 /// Reject buffers exceeding the pre-parse byte cap (guardrail #2 — pre-parse).
 ///
 /// Called **before** the FlatBuffers verifier; an adversarially large buffer
-/// never reaches the parser.
+/// never reaches the parser. Charges `meter` the flat per-message weight
+/// (guardrail #7) before checking the size cap.
 #[inline]
-pub fn check_message_size(buf: &[u8]) -> Result<(), SizeError> {
+pub fn check_message_size(buf: &[u8], meter: &mut WeightMeter) -> Result<(), SizeError> {
+    if let Err(e) = meter.charge(WEIGHT_BASE_MESSAGE) {
+        return Err(SizeError::WeightExceeded {
+            got: e.got,
+            budget: e.budget,
+        });
+    }
     if buf.len() > MAX_MESSAGE_BYTES {
         return Err(SizeError::MessageTooLarge { got: buf.len() });
     }
@@ -71,9 +95,17 @@ pub fn check_message_size(buf: &[u8]) -> Result<(), SizeError> {
 }
 
 /// Reject a parsed `DomainContext` whose embedding vector exceeds the cap
-/// (guardrail #2 — post-parse).
-pub fn check_embedding_len(ctx: &DomainContext<'_>) -> Result<(), OomError> {
+/// (guardrail #2 — post-parse). Charges `meter` a weight proportional to the
+/// embedding length (guardrail #7) before iterating it.
+pub fn check_embedding_len(ctx: &DomainContext<'_>, meter: &mut WeightMeter) -> Result<(), OomError> {
     if let Some(emb) = ctx.embedding() {
+        let weight = WEIGHT_PER_EMBEDDING_ELEMENT.saturating_mul(emb.len() as u64);
+        if let Err(e) = meter.charge(weight) {
+            return Err(OomError::WeightExceeded {
+                got: e.got,
+                budget: e.budget,
+            });
+        }
         if emb.len() > MAX_EMBEDDING_LEN {
             return Err(OomError::EmbeddingTooLong { got: emb.len() });
         }
@@ -106,11 +138,20 @@ pub fn xxh3_digest_eq(a: &Xxh3Digest, b: &Xxh3Digest) -> bool {
 /// 2. **Strict monotonicity** — `timestamp_ns` must be strictly greater than
 ///    `last_seen_ts_ns` for this (origin, public_key) pair, preventing exact
 ///    replay of a still-fresh message.
+///
+/// Charges `meter` a fixed weight (guardrail #7) before either comparison.
 pub fn check_freshness(
     timestamp_ns: u64,
     last_seen_ts_ns: u64,
     now_ns: u64,
+    meter: &mut WeightMeter,
 ) -> Result<(), ProvenanceError> {
+    if let Err(e) = meter.charge(WEIGHT_CHECK_FRESHNESS) {
+        return Err(ProvenanceError::WeightExceeded {
+            got: e.got,
+            budget: e.budget,
+        });
+    }
     let age = now_ns.saturating_sub(timestamp_ns);
     if age > FRESHNESS_WINDOW_NS {
         return Err(ProvenanceError::StaleTimestamp);
@@ -130,7 +171,19 @@ pub fn check_freshness(
 ///
 /// `last_seen == 0` means "first-ever message from this origin" and is always
 /// accepted regardless of `current`.
-pub fn check_chain_height(current: u64, last_seen: u64) -> Result<(), ProvenanceError> {
+///
+/// Charges `meter` a fixed weight (guardrail #7) before comparing.
+pub fn check_chain_height(
+    current: u64,
+    last_seen: u64,
+    meter: &mut WeightMeter,
+) -> Result<(), ProvenanceError> {
+    if let Err(e) = meter.charge(WEIGHT_CHECK_CHAIN_HEIGHT) {
+        return Err(ProvenanceError::WeightExceeded {
+            got: e.got,
+            budget: e.budget,
+        });
+    }
     if last_seen != 0 && current <= last_seen {
         return Err(ProvenanceError::ChainHeightRegressed);
     }
@@ -149,9 +202,22 @@ pub fn check_chain_height(current: u64, last_seen: u64) -> Result<(), Provenance
 /// [`check_chain_height`] for rationale).
 ///
 /// Uses `verify_strict` which rejects low-order-component malleability.
-pub fn verify_ed25519(record: &ProvenanceRecord<'_>) -> Result<(), ProvenanceError> {
+/// Charges `meter` a fixed large weight (guardrail #7) before verifying,
+/// since ED25519 verification is the single most expensive operation on the
+/// critical path.
+pub fn verify_ed25519(
+    record: &ProvenanceRecord<'_>,
+    meter: &mut WeightMeter,
+) -> Result<(), ProvenanceError> {
     use ed25519_dalek::{Signature, VerifyingKey};
 
+    if let Err(e) = meter.charge(WEIGHT_VERIFY_ED25519) {
+        return Err(ProvenanceError::WeightExceeded {
+            got: e.got,
+            budget: e.budget,
+        });
+    }
+
     let digest = record
         .content_digest()
         .ok_or(ProvenanceError::MissingContentDigest)?;
@@ -187,10 +253,14 @@ pub fn verify_ed25519(record: &ProvenanceRecord<'_>) -> Result<(), ProvenanceErr
 /// - **`EXPECTED_PQ_SIG_LEN == 0`**: validation disabled; any length accepted.
 /// - **Non-empty, `EXPECTED_PQ_SIG_LEN > 0`**: must equal exactly
 ///   `EXPECTED_PQ_SIG_LEN`.  A truncated or padded PQ sig indicates tampering.
-pub fn validate_pq_signature(pq_sig: Option<&[u8]>) -> Result<(), PqSigError> {
-    if EXPECTED_PQ_SIG_LEN == 0 {
-        return Ok(()); // bootstrap: length validation disabled
-    }
+///
+/// Charges `meter` a weight proportional to `pq_sig`'s length (guardrail #7)
+/// before validating it, since PQ signatures are large relative to a
+/// classical ED25519 signature.
+pub fn validate_pq_signature(
+    pq_sig: Option<&[u8]>,
+    meter: &mut WeightMeter,
+) -> Result<(), PqSigError> {
     let bytes = match pq_sig {
         None => return Ok(()),
         Some(b) => b,
@@ -198,6 +268,18 @@ pub fn validate_pq_signature(pq_sig: Option<&[u8]>) -> Result<(), PqSigError> {
     if bytes.is_empty() {
         return Ok(());
     }
+
+    let weight = WEIGHT_PER_PQ_SIG_BYTE.saturating_mul(bytes.len() as u64);
+    if let Err(e) = meter.charge(weight) {
+        return Err(PqSigError::WeightExceeded {
+            got: e.got,
+            budget: e.budget,
+        });
+    }
+
+    if EXPECTED_PQ_SIG_LEN == 0 {
+        return Ok(()); // bootstrap: length validation disabled
+    }
     if bytes.len() != EXPECTED_PQ_SIG_LEN {
         return Err(PqSigError::InvalidLength {
             got: bytes.len(),
@@ -207,6 +289,83 @@ pub fn validate_pq_signature(pq_sig: Option<&[u8]>) -> Result<(), PqSigError> {
     Ok(())
 }
 
+// ---------------------------------------------------------------------------
+// Guardrail #5 — per-origin reputation scoring
+// ---------------------------------------------------------------------------
+
+/// Tracking state an origin is in, derived from its decayed reputation score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OriginState {
+    /// Score is above `FORCED_DISCONNECT_THRESHOLD` — traffic flows normally.
+    Healthy,
+    /// Score is at or below `FORCED_DISCONNECT_THRESHOLD` but above
+    /// `BANNED_THRESHOLD` — replay tracking for this origin was dropped.
+    ForcedDisconnect,
+    /// Score is at or below `BANNED_THRESHOLD` — traffic is short-circuited
+    /// until `ban_until_ns` and the score has decayed back above threshold.
+    Banned,
+}
+
+/// Decay `score` toward 0 over elapsed time `dt_ns` (guardrail #5).
+///
+/// `score *= exp(-lambda * dt_ns)` where `lambda = ln(2) / half_life_ns`, so a
+/// score left untouched for exactly one half-life is cut in half. A
+/// `half_life_ns` of 0 disables decay (returns `score` unchanged).
+#[inline]
+pub fn decay_score(score: f64, dt_ns: u64, half_life_ns: u64) -> f64 {
+    if half_life_ns == 0 {
+        return score;
+    }
+    let lambda = std::f64::consts::LN_2 / half_life_ns as f64;
+    score * (-lambda * dt_ns as f64).exp()
+}
+
+/// Decay `score` since its last update, then apply a signed reputation delta
+/// (guardrail #5).
+///
+/// Decaying before applying the delta is what makes isolated penalties
+/// forgivable while sustained abuse still accumulates: a flood of penalties
+/// arriving faster than the half-life outpaces the decay between them.
+#[inline]
+pub fn apply_score_delta(score: f64, dt_ns: u64, half_life_ns: u64, delta: f64) -> f64 {
+    decay_score(score, dt_ns, half_life_ns) + delta
+}
+
+/// Classify a (decayed, post-delta) score against the configured thresholds
+/// (guardrail #5).
+#[inline]
+pub fn classify_origin_state(score: f64) -> OriginState {
+    if score <= BANNED_THRESHOLD {
+        OriginState::Banned
+    } else if score <= FORCED_DISCONNECT_THRESHOLD {
+        OriginState::ForcedDisconnect
+    } else {
+        OriginState::Healthy
+    }
+}
+
+/// Short-circuit traffic from a still-banned origin (guardrail #5).
+///
+/// Traffic stays short-circuited until *both* the ban timer has lapsed and
+/// the reputation score has decayed back above `BANNED_THRESHOLD` — either
+/// condition alone keeps the ban in effect. Checking only the timer would
+/// let a still-under-threshold origin's very next message through the
+/// instant `ban_until_ns` passes, even though its score says it hasn't
+/// earned that; `score` must be decayed to `now_ns` by the caller first.
+pub fn check_origin_ban(
+    state: OriginState,
+    score: f64,
+    ban_until_ns: u64,
+    now_ns: u64,
+) -> Result<(), ProvenanceError> {
+    if state == OriginState::Banned
+        && (now_ns < ban_until_ns || classify_origin_state(score) == OriginState::Banned)
+    {
+        return Err(ProvenanceError::OriginBanned);
+    }
+    Ok(())
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -221,18 +380,40 @@ mod tests {
     #[test]
     fn message_size_at_cap_is_accepted() {
         let buf = vec![0u8; MAX_MESSAGE_BYTES];
-        assert!(check_message_size(&buf).is_ok());
+        let mut meter = WeightMeter::default();
+        assert!(check_message_size(&buf, &mut meter).is_ok());
     }
 
     #[test]
     fn message_size_one_over_cap_is_rejected() {
         let buf = vec![0u8; MAX_MESSAGE_BYTES + 1];
+        let mut meter = WeightMeter::default();
         assert!(matches!(
-            check_message_size(&buf),
+            check_message_size(&buf, &mut meter),
             Err(SizeError::MessageTooLarge { got }) if got == MAX_MESSAGE_BYTES + 1
         ));
     }
 
+    // --- Guardrail #7 ---
+
+    #[test]
+    fn message_size_charges_base_weight_even_when_accepted() {
+        let buf = vec![0u8; 16];
+        let mut meter = WeightMeter::default();
+        assert!(check_message_size(&buf, &mut meter).is_ok());
+        assert_eq!(meter.total(), WEIGHT_BASE_MESSAGE);
+    }
+
+    #[test]
+    fn check_message_size_rejects_once_weight_budget_exhausted() {
+        let buf = vec![0u8; 16];
+        let mut meter = WeightMeter::new(WEIGHT_BASE_MESSAGE - 1);
+        assert!(matches!(
+            check_message_size(&buf, &mut meter),
+            Err(SizeError::WeightExceeded { .. })
+        ));
+    }
+
     // --- Guardrail #3 ---
 
     #[test]
@@ -258,15 +439,17 @@ mod tests {
     fn fresh_monotonic_timestamp_is_accepted() {
         let now = 60_000_000_000u64; // 60 s
         let ts = now - 1_000_000; // 1 ms old
-        assert!(check_freshness(ts, 0, now).is_ok());
+        let mut meter = WeightMeter::default();
+        assert!(check_freshness(ts, 0, now, &mut meter).is_ok());
     }
 
     #[test]
     fn stale_timestamp_is_rejected() {
         let now = 60_000_000_000u64;
         let ts = 0u64; // 60 s old — exceeds 30 s window
+        let mut meter = WeightMeter::default();
         assert!(matches!(
-            check_freshness(ts, 0, now),
+            check_freshness(ts, 0, now, &mut meter),
             Err(ProvenanceError::StaleTimestamp)
         ));
     }
@@ -275,54 +458,155 @@ mod tests {
     fn replay_of_same_timestamp_is_rejected() {
         let now = 60_000_000_000u64;
         let ts = now - 1_000_000;
-        assert!(check_freshness(ts, 0, now).is_ok());
+        let mut meter = WeightMeter::default();
+        assert!(check_freshness(ts, 0, now, &mut meter).is_ok());
         // Replay with identical timestamp must fail.
         assert!(matches!(
-            check_freshness(ts, ts, now),
+            check_freshness(ts, ts, now, &mut meter),
             Err(ProvenanceError::NonMonotonicTimestamp)
         ));
     }
 
+    #[test]
+    fn check_freshness_rejects_once_weight_budget_exhausted() {
+        let mut meter = WeightMeter::new(WEIGHT_CHECK_FRESHNESS - 1);
+        assert!(matches!(
+            check_freshness(1, 0, 1, &mut meter),
+            Err(ProvenanceError::WeightExceeded { .. })
+        ));
+    }
+
     // --- Guardrail #1: chain height ---
 
     #[test]
     fn chain_height_first_message_is_accepted() {
-        assert!(check_chain_height(0, 0).is_ok());
-        assert!(check_chain_height(1, 0).is_ok());
+        let mut meter = WeightMeter::default();
+        assert!(check_chain_height(0, 0, &mut meter).is_ok());
+        assert!(check_chain_height(1, 0, &mut meter).is_ok());
     }
 
     #[test]
     fn chain_height_regression_is_rejected() {
+        let mut meter = WeightMeter::default();
         assert!(matches!(
-            check_chain_height(5, 10),
+            check_chain_height(5, 10, &mut meter),
             Err(ProvenanceError::ChainHeightRegressed)
         ));
     }
 
     #[test]
     fn chain_height_equal_is_rejected() {
+        let mut meter = WeightMeter::default();
         assert!(matches!(
-            check_chain_height(10, 10),
+            check_chain_height(10, 10, &mut meter),
             Err(ProvenanceError::ChainHeightRegressed)
         ));
     }
 
+    #[test]
+    fn check_chain_height_rejects_once_weight_budget_exhausted() {
+        let mut meter = WeightMeter::new(WEIGHT_CHECK_CHAIN_HEIGHT - 1);
+        assert!(matches!(
+            check_chain_height(1, 0, &mut meter),
+            Err(ProvenanceError::WeightExceeded { .. })
+        ));
+    }
+
     // --- Guardrail #4 ---
 
     #[test]
     fn pq_sig_absent_is_accepted() {
-        assert!(validate_pq_signature(None).is_ok());
+        let mut meter = WeightMeter::default();
+        assert!(validate_pq_signature(None, &mut meter).is_ok());
     }
 
     #[test]
     fn pq_sig_empty_slice_is_accepted() {
-        assert!(validate_pq_signature(Some(&[])).is_ok());
+        let mut meter = WeightMeter::default();
+        assert!(validate_pq_signature(Some(&[]), &mut meter).is_ok());
     }
 
     #[test]
     fn pq_sig_bootstrap_mode_accepts_any_length() {
         assert_eq!(EXPECTED_PQ_SIG_LEN, 0, "test assumes bootstrap/disabled mode");
-        assert!(validate_pq_signature(Some(&[0u8; 3293])).is_ok());
-        assert!(validate_pq_signature(Some(&[0u8; 1])).is_ok());
+        let mut meter = WeightMeter::default();
+        assert!(validate_pq_signature(Some(&[0u8; 3293]), &mut meter).is_ok());
+        assert!(validate_pq_signature(Some(&[0u8; 1]), &mut meter).is_ok());
+    }
+
+    // --- Guardrail #5: reputation scoring ---
+
+    #[test]
+    fn score_decays_toward_zero_over_one_half_life() {
+        let half_life_ns = 1_000_000_000u64; // 1 s
+        let decayed = decay_score(10.0, half_life_ns, half_life_ns);
+        assert!((decayed - 5.0).abs() < 1e-9, "decayed={decayed}");
+    }
+
+    #[test]
+    fn zero_half_life_disables_decay() {
+        assert_eq!(decay_score(-7.0, 1_000_000_000, 0), -7.0);
+    }
+
+    #[test]
+    fn sustained_penalties_faster_than_half_life_accumulate() {
+        let half_life_ns = 1_000_000_000u64;
+        let mut score = 0.0;
+        for _ in 0..5 {
+            // Each penalty lands well inside the half-life, so decay barely offsets it.
+            score = apply_score_delta(score, half_life_ns / 100, half_life_ns, -5.0);
+        }
+        assert!(score < BANNED_THRESHOLD, "score={score} should have crossed the ban threshold");
+    }
+
+    #[test]
+    fn isolated_penalty_forgiven_after_several_half_lives() {
+        let half_life_ns = 1_000_000_000u64;
+        let score = apply_score_delta(0.0, 0, half_life_ns, FORCED_DISCONNECT_THRESHOLD);
+        let forgiven = decay_score(score, half_life_ns * 10, half_life_ns);
+        assert!(forgiven > FORCED_DISCONNECT_THRESHOLD, "forgiven={forgiven}");
+    }
+
+    #[test]
+    fn classify_origin_state_thresholds() {
+        assert_eq!(classify_origin_state(0.0), OriginState::Healthy);
+        assert_eq!(
+            classify_origin_state(FORCED_DISCONNECT_THRESHOLD),
+            OriginState::ForcedDisconnect
+        );
+        assert_eq!(classify_origin_state(BANNED_THRESHOLD), OriginState::Banned);
+    }
+
+    #[test]
+    fn banned_origin_rejected_until_both_timer_and_score_clear() {
+        // Timer still running, score still banned — rejected.
+        assert!(matches!(
+            check_origin_ban(OriginState::Banned, BANNED_THRESHOLD, 100, 50),
+            Err(ProvenanceError::OriginBanned)
+        ));
+        // Timer lapsed, score recovered — the only combination let through.
+        assert!(check_origin_ban(OriginState::Banned, 0.0, 100, 150).is_ok());
+        assert!(check_origin_ban(OriginState::Healthy, 0.0, 100, 50).is_ok());
+    }
+
+    #[test]
+    fn banned_origin_still_rejected_if_score_has_not_recovered_when_timer_lapses() {
+        // Timer has lapsed (now_ns >= ban_until_ns) but the decayed score is
+        // still at/below BANNED_THRESHOLD — a timer-only check would let
+        // this through; the score gate must keep it rejected.
+        assert!(matches!(
+            check_origin_ban(OriginState::Banned, BANNED_THRESHOLD, 100, 150),
+            Err(ProvenanceError::OriginBanned)
+        ));
+    }
+
+    #[test]
+    fn banned_origin_still_rejected_if_timer_has_not_lapsed_even_with_recovered_score() {
+        // Score has decayed back above threshold but the ban timer hasn't
+        // lapsed yet — still rejected.
+        assert!(matches!(
+            check_origin_ban(OriginState::Banned, 0.0, 100, 50),
+            Err(ProvenanceError::OriginBanned)
+        ));
     }
 }
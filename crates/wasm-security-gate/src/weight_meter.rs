@@ -0,0 +1,109 @@
+//! Deterministic per-message compute-budget metering (guardrail #7).
+//!
+//! Bounds total verification CPU per message with one auditable knob instead
+//! of several loosely-related limits (message size, verifier depth, table
+//! count). Each guardrail charges a `u64` weight as it runs — a base weight
+//! per message plus `base_op + per_unit * count` for variable-cost
+//! operations — and [`WeightMeter::charge`] rejects the moment the running
+//! total would cross `MAX_MESSAGE_WEIGHT`, before the step it prices runs.
+
+use crate::config::MAX_MESSAGE_WEIGHT;
+
+/// The running weight total would exceed the meter's budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeightExceeded {
+    pub got: u64,
+    pub budget: u64,
+}
+
+/// Running compute-weight total for a single message.
+pub struct WeightMeter {
+    total: u64,
+    budget: u64,
+}
+
+impl WeightMeter {
+    /// Create a meter against an explicit `budget`.
+    pub fn new(budget: u64) -> Self {
+        Self { total: 0, budget }
+    }
+
+    /// Charge `weight` with saturating addition, rejecting before the charge
+    /// is committed if the running total would cross `budget`.
+    pub fn charge(&mut self, weight: u64) -> Result<(), WeightExceeded> {
+        let next = self.total.saturating_add(weight);
+        if next > self.budget {
+            return Err(WeightExceeded {
+                got: next,
+                budget: self.budget,
+            });
+        }
+        self.total = next;
+        Ok(())
+    }
+
+    /// The running weight total charged so far.
+    #[inline]
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// The configured budget.
+    #[inline]
+    pub fn budget(&self) -> u64 {
+        self.budget
+    }
+}
+
+impl Default for WeightMeter {
+    /// A meter against the gate's configured [`MAX_MESSAGE_WEIGHT`].
+    fn default() -> Self {
+        Self::new(MAX_MESSAGE_WEIGHT)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn charges_accumulate_under_budget() {
+        let mut meter = WeightMeter::new(100);
+        assert!(meter.charge(40).is_ok());
+        assert!(meter.charge(40).is_ok());
+        assert_eq!(meter.total(), 80);
+    }
+
+    #[test]
+    fn charge_crossing_budget_is_rejected_and_not_committed() {
+        let mut meter = WeightMeter::new(100);
+        assert!(meter.charge(90).is_ok());
+        assert_eq!(
+            meter.charge(20),
+            Err(WeightExceeded {
+                got: 110,
+                budget: 100
+            })
+        );
+        // The rejected charge must not be committed to the running total.
+        assert_eq!(meter.total(), 90);
+    }
+
+    #[test]
+    fn charge_at_exact_budget_is_accepted() {
+        let mut meter = WeightMeter::new(100);
+        assert!(meter.charge(100).is_ok());
+        assert_eq!(meter.total(), 100);
+    }
+
+    #[test]
+    fn saturating_addition_never_panics_on_overflow() {
+        let mut meter = WeightMeter::new(u64::MAX);
+        assert!(meter.charge(u64::MAX).is_ok());
+        assert!(meter.charge(u64::MAX).is_err());
+    }
+}